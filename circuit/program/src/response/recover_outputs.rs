@@ -0,0 +1,383 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The on-chain data needed to recover a single output, keyed by the shape its `ValueType` takes
+/// in [`Response::process_outputs_from_callback`]. `Constant` and `Public` outputs are already
+/// cleartext on-chain; `Private` outputs are only available as a ciphertext; `Record` and
+/// `ExternalRecord` outputs are already known in full and only need their output ID re-derived.
+pub enum OutputRecovery<N: Network> {
+    /// A `Constant` or `Public` output's already-public plaintext.
+    Plaintext(console::Plaintext<N>),
+    /// A `Private` output's on-chain ciphertext, to be decrypted with the output view key.
+    Ciphertext(console::Ciphertext<N>),
+    /// A `Record` or `ExternalRecord` output's already-known record.
+    Record(console::Record<N, console::Plaintext<N>>),
+}
+
+/// A single transition's inputs to [`Response::recover_many`].
+pub struct RecoverOutputsInput<N: Network> {
+    pub program_id: console::ProgramID<N>,
+    pub num_inputs: usize,
+    pub tvk: console::Field<N>,
+    pub tcm: console::Field<N>,
+    pub output_ids: Vec<console::OutputID<N>>,
+    pub recoveries: Vec<OutputRecovery<N>>,
+    pub output_types: Vec<console::ValueType<N>>,
+}
+
+impl<A: Aleo> Response<A> {
+    /// Recovers and verifies the plaintext outputs of a single transition, given the transition
+    /// view key `tvk`. This is the inverse of [`Response::process_outputs_from_callback`]: for
+    /// each output, it re-derives `output_view_key = Hash(tvk || index)`, decrypts `Private`
+    /// ciphertexts, rebuilds `Constant`/`Public` preimages, and re-derives record commitments —
+    /// then checks the reconstructed `OutputID` against the one claimed on-chain. Returns an error
+    /// on the first mismatch, since the caller does not yet know whether `tvk` belongs to this
+    /// transition.
+    pub fn recover_outputs(
+        program_id: &console::ProgramID<A::Network>,
+        num_inputs: usize,
+        tvk: &console::Field<A::Network>,
+        tcm: &console::Field<A::Network>,
+        output_ids: &[console::OutputID<A::Network>],
+        recoveries: &[OutputRecovery<A::Network>],
+        output_types: &[console::ValueType<A::Network>],
+    ) -> Result<Vec<console::Value<A::Network>>> {
+        ensure!(output_ids.len() == output_types.len(), "Mismatching number of output IDs and output types");
+        ensure!(recoveries.len() == output_types.len(), "Mismatching number of recoveries and output types");
+
+        output_ids
+            .iter()
+            .zip_eq(recoveries)
+            .zip_eq(output_types)
+            .enumerate()
+            .map(|(index, ((output_id, recovery), output_type))| {
+                let output_index = console::Field::from_u16((num_inputs + index) as u16);
+                Self::recover_output(program_id, tvk, tcm, &output_index, output_id, recovery, output_type)
+            })
+            .collect()
+    }
+
+    /// Batched variant of [`Response::recover_outputs`] that scans many transitions for ones
+    /// belonging to the caller. It derives every `Private` output's view key for every transition
+    /// up front, in its own pass separate from decryption - `Private` outputs are the only kind
+    /// that ever consume a view key, so non-`Private` outputs cost no extra `hash_psd2` calls
+    /// versus the single-transition path. A transition is treated as "not mine" and its remaining
+    /// outputs are left undecrypted as soon as its first `OutputID` fails to match.
+    pub fn recover_many(transitions: &[RecoverOutputsInput<A::Network>]) -> Vec<Option<Vec<console::Value<A::Network>>>> {
+        // Derive each `Private` output's view key up front, separating view-key derivation from
+        // the decryption loop below. Non-`Private` outputs get `None`, since `recover_output` never
+        // touches a view key for them.
+        let view_keys: Vec<Vec<Option<Result<console::Field<A::Network>>>>> = transitions
+            .iter()
+            .map(|transition| {
+                (0..transition.output_ids.len())
+                    .map(|i| match transition.output_types.get(i) {
+                        Some(console::ValueType::Private(..)) => {
+                            let output_index = console::Field::from_u16((transition.num_inputs + i) as u16);
+                            Some(A::Network::hash_psd2(&[transition.tvk, output_index]))
+                        }
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        transitions
+            .iter()
+            .zip_eq(view_keys)
+            .map(|(transition, view_keys)| Self::recover_transition(transition, &view_keys).ok())
+            .collect()
+    }
+
+    /// Recovers a single transition's outputs using precomputed `Private`-output view keys (`None`
+    /// for every other output kind), short-circuiting on the first `OutputID` mismatch without
+    /// decrypting the remaining outputs.
+    fn recover_transition(
+        transition: &RecoverOutputsInput<A::Network>,
+        view_keys: &[Option<Result<console::Field<A::Network>>>],
+    ) -> Result<Vec<console::Value<A::Network>>> {
+        ensure!(view_keys.len() == transition.output_ids.len(), "Mismatching number of output view keys");
+
+        transition
+            .output_ids
+            .iter()
+            .zip_eq(&transition.recoveries)
+            .zip_eq(&transition.output_types)
+            .enumerate()
+            .map(|(index, ((output_id, recovery), output_type))| {
+                let output_index = console::Field::from_u16((transition.num_inputs + index) as u16);
+                match output_type {
+                    // Private outputs need the precomputed output view key; reuse it instead of
+                    // re-deriving `Hash(tvk || index)` here.
+                    console::ValueType::Private(..) => match &view_keys[index] {
+                        Some(view_key) => Self::recover_private_output(view_key.clone()?, output_id, recovery),
+                        None => bail!("Missing precomputed output view key for output {index}"),
+                    },
+                    _ => Self::recover_output(
+                        &transition.program_id,
+                        &transition.tvk,
+                        &transition.tcm,
+                        &output_index,
+                        output_id,
+                        recovery,
+                        output_type,
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    /// Recovers and verifies a single output, dispatching on its `ValueType`.
+    fn recover_output(
+        program_id: &console::ProgramID<A::Network>,
+        tvk: &console::Field<A::Network>,
+        tcm: &console::Field<A::Network>,
+        output_index: &console::Field<A::Network>,
+        output_id: &console::OutputID<A::Network>,
+        recovery: &OutputRecovery<A::Network>,
+        output_type: &console::ValueType<A::Network>,
+    ) -> Result<console::Value<A::Network>> {
+        match output_type {
+            // For a constant output, the plaintext is already public; re-hash it with `tcm`.
+            console::ValueType::Constant(..) => {
+                let OutputRecovery::Plaintext(plaintext) = recovery else {
+                    bail!("Expected a plaintext recovery for a constant output")
+                };
+                let output = console::Value::Plaintext(plaintext.clone());
+                let mut preimage = output.to_fields()?;
+                preimage.push(*tcm);
+                preimage.push(*output_index);
+
+                ensure!(*output_id == console::OutputID::constant(A::Network::hash_psd8(&preimage)?), "Mismatching constant output ID");
+                Ok(output)
+            }
+            // For a public output, the plaintext is already public; re-hash it with `tcm`.
+            console::ValueType::Public(..) => {
+                let OutputRecovery::Plaintext(plaintext) = recovery else {
+                    bail!("Expected a plaintext recovery for a public output")
+                };
+                let output = console::Value::Plaintext(plaintext.clone());
+                let mut preimage = output.to_fields()?;
+                preimage.push(*tcm);
+                preimage.push(*output_index);
+
+                ensure!(*output_id == console::OutputID::public(A::Network::hash_psd8(&preimage)?), "Mismatching public output ID");
+                Ok(output)
+            }
+            // For a private output, derive the output view key from `tvk`, then decrypt.
+            console::ValueType::Private(..) => {
+                let output_view_key = A::Network::hash_psd2(&[*tvk, *output_index])?;
+                Self::recover_private_output(output_view_key, output_id, recovery)
+            }
+            // For a record output, the record is already known; re-derive its commitment.
+            console::ValueType::Record(record_name) => {
+                let OutputRecovery::Record(record) = recovery else { bail!("Expected a record recovery for a record output") };
+                let commitment = record.to_commitment(program_id, record_name)?;
+
+                ensure!(*output_id == console::OutputID::external_record(commitment), "Mismatching record output ID");
+                Ok(console::Value::Record(record.clone()))
+            }
+            // For an external record output, re-hash the record with `tvk`.
+            console::ValueType::ExternalRecord(..) => {
+                let OutputRecovery::Record(record) = recovery else {
+                    bail!("Expected a record recovery for an external record output")
+                };
+                let output = console::Value::Record(record.clone());
+                let mut preimage = output.to_fields()?;
+                preimage.push(*tvk);
+                preimage.push(*output_index);
+
+                ensure!(
+                    *output_id == console::OutputID::external_record(A::Network::hash_psd8(&preimage)?),
+                    "Mismatching external record output ID"
+                );
+                Ok(output)
+            }
+        }
+    }
+
+    /// Decrypts and verifies a `Private` output, given its precomputed output view key.
+    fn recover_private_output(
+        output_view_key: console::Field<A::Network>,
+        output_id: &console::OutputID<A::Network>,
+        recovery: &OutputRecovery<A::Network>,
+    ) -> Result<console::Value<A::Network>> {
+        let OutputRecovery::Ciphertext(ciphertext) = recovery else {
+            bail!("Expected a ciphertext recovery for a private output")
+        };
+
+        ensure!(
+            *output_id == console::OutputID::private(A::Network::hash_psd8(&ciphertext.to_fields()?)?),
+            "Mismatching private output ID"
+        );
+
+        let plaintext = ciphertext.decrypt_symmetric(output_view_key)?;
+        Ok(console::Value::Plaintext(plaintext))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    /// Builds a transition with one `Constant`, one `Public`, and one `Private` output, with
+    /// output IDs and recoveries derived exactly as `recover_output`/`recover_private_output`
+    /// expect - the same preimages `process_outputs_from_callback` would have produced - so that
+    /// recovery only succeeds if those hashes are reproduced correctly.
+    fn sample_transition(
+        num_inputs: usize,
+        tvk: console::Field<<Circuit as Environment>::Network>,
+        tcm: console::Field<<Circuit as Environment>::Network>,
+    ) -> Result<RecoverOutputsInput<<Circuit as Environment>::Network>> {
+        use console::Network;
+
+        let program_id = console::ProgramID::from_str("test.aleo")?;
+
+        let constant = console::Plaintext::from_str("1u64")?;
+        let public = console::Plaintext::from_str("2u64")?;
+        let private = console::Plaintext::from_str("3u64")?;
+
+        let output_types = vec![
+            console::ValueType::from_str("a.constant")?,
+            console::ValueType::from_str("b.public")?,
+            console::ValueType::from_str("c.private")?,
+        ];
+
+        // Constant output ID: Hash(plaintext || tcm || index).
+        let constant_index = console::Field::from_u16(num_inputs as u16);
+        let mut constant_preimage = console::Value::Plaintext(constant.clone()).to_fields()?;
+        constant_preimage.push(tcm);
+        constant_preimage.push(constant_index);
+        let constant_id = console::OutputID::constant(
+            <Circuit as Environment>::Network::hash_psd8(&constant_preimage)?,
+        );
+
+        // Public output ID: Hash(plaintext || tcm || index).
+        let public_index = console::Field::from_u16((num_inputs + 1) as u16);
+        let mut public_preimage = console::Value::Plaintext(public.clone()).to_fields()?;
+        public_preimage.push(tcm);
+        public_preimage.push(public_index);
+        let public_id =
+            console::OutputID::public(<Circuit as Environment>::Network::hash_psd8(&public_preimage)?);
+
+        // Private output ID: Hash(ciphertext), where ciphertext = Encrypt(plaintext, Hash(tvk || index)).
+        let private_index = console::Field::from_u16((num_inputs + 2) as u16);
+        let output_view_key = <Circuit as Environment>::Network::hash_psd2(&[tvk, private_index])?;
+        let ciphertext = private.encrypt_symmetric(output_view_key);
+        let private_id = console::OutputID::private(<Circuit as Environment>::Network::hash_psd8(
+            &ciphertext.to_fields()?,
+        )?);
+
+        Ok(RecoverOutputsInput {
+            program_id,
+            num_inputs,
+            tvk,
+            tcm,
+            output_ids: vec![constant_id, public_id, private_id],
+            recoveries: vec![
+                OutputRecovery::Plaintext(constant),
+                OutputRecovery::Plaintext(public),
+                OutputRecovery::Ciphertext(ciphertext),
+            ],
+            output_types,
+        })
+    }
+
+    #[test]
+    fn test_recover_outputs_round_trip() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let tvk = console::Field::rand(rng);
+        let tcm = console::Field::rand(rng);
+
+        let transition = sample_transition(4, tvk, tcm)?;
+        let outputs = Response::<Circuit>::recover_outputs(
+            &transition.program_id,
+            transition.num_inputs,
+            &transition.tvk,
+            &transition.tcm,
+            &transition.output_ids,
+            &transition.recoveries,
+            &transition.output_types,
+        )?;
+
+        assert_eq!(outputs[0], console::Value::Plaintext(console::Plaintext::from_str("1u64")?));
+        assert_eq!(outputs[1], console::Value::Plaintext(console::Plaintext::from_str("2u64")?));
+        assert_eq!(outputs[2], console::Value::Plaintext(console::Plaintext::from_str("3u64")?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_recover_outputs_rejects_mismatched_id() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let tvk = console::Field::rand(rng);
+        let tcm = console::Field::rand(rng);
+
+        let mut transition = sample_transition(4, tvk, tcm)?;
+        // Corrupt the private output's claimed ID.
+        transition.output_ids[2] = console::OutputID::private(console::Field::rand(rng));
+
+        let result = Response::<Circuit>::recover_outputs(
+            &transition.program_id,
+            transition.num_inputs,
+            &transition.tvk,
+            &transition.tcm,
+            &transition.output_ids,
+            &transition.recoveries,
+            &transition.output_types,
+        );
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_recover_many_only_derives_view_keys_for_private_outputs() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // One transition that belongs to this `tvk`, and one that does not: `recover_many` must
+        // recover the first and report `None` for the second, without mixing up the precomputed
+        // view key slots between their non-`Private` and `Private` outputs.
+        let tvk = console::Field::rand(rng);
+        let tcm = console::Field::rand(rng);
+        let mine = sample_transition(4, tvk, tcm)?;
+
+        let other_tvk = console::Field::rand(rng);
+        let mut not_mine = sample_transition(4, other_tvk, tcm)?;
+        // Pretend the recipient's `tvk` does not match this transition, so it must fail to recover.
+        not_mine.tvk = tvk;
+
+        let expected = Response::<Circuit>::recover_outputs(
+            &mine.program_id,
+            mine.num_inputs,
+            &mine.tvk,
+            &mine.tcm,
+            &mine.output_ids,
+            &mine.recoveries,
+            &mine.output_types,
+        )?;
+
+        let results = Response::<Circuit>::recover_many(&[mine, not_mine]);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap(), &expected);
+        assert!(results[1].is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> FromBits for Scalar<E> {
+    type Boolean = Boolean<E>;
+
+    /// Initializes a new scalar from a list of **little-endian** bits.
+    ///   - If `bits_le` is longer than `E::Scalar::size_in_bits()`, the excess bits are enforced to be `0`s.
+    ///   - If `bits_le` is shorter than `E::Scalar::size_in_bits()`, it is padded with `0`s up to scalar size.
+    ///
+    /// This is the in-circuit counterpart to the console implementation's modulus check: because the
+    /// scalar field's order is not a power of two, a full-length bit vector does not uniquely determine
+    /// a canonical scalar, so this gadget enforces the reconstructed integer is strictly less than
+    /// `Scalar::MODULUS`, rather than silently wrapping.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        // Retrieve the data and scalar size.
+        let size_in_data_bits = Scalar::<E>::size_in_data_bits();
+        let size_in_bits = Scalar::<E>::size_in_bits();
+
+        // Ensure the list of booleans is within the allowed size in bits.
+        let num_bits = bits_le.len();
+        if num_bits > size_in_bits {
+            // Enforce that all excess bits are zero.
+            for bit in &bits_le[size_in_bits..] {
+                E::assert_eq(bit, &Boolean::constant(false));
+            }
+        }
+
+        // Construct the sanitized list of bits, resizing up if necessary.
+        let mut bits_le = bits_le.iter().take(size_in_bits).cloned().collect::<Vec<_>>();
+        bits_le.resize(size_in_bits, Boolean::constant(false));
+
+        // If `num_bits` is greater than `size_in_data_bits`, enforce that the bits are canonical,
+        // i.e. that the reconstructed integer is less than `Scalar::MODULUS`.
+        if num_bits > size_in_data_bits {
+            Self::enforce_less_than_modulus(&bits_le);
+        }
+
+        // Recover the scalar from the bits.
+        Self::from_field(Field::from_bits_le(&bits_le))
+    }
+
+    /// Initializes a new scalar from a list of big-endian bits *without* leading zeros.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        // Reverse the given bits from big-endian into little-endian.
+        // Note: This is safe as the bit representation is consistent (there are no leading zeros).
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+impl<E: Environment> Scalar<E> {
+    /// Enforces that `bits_le`, a little-endian bit vector of length `Scalar::size_in_bits()`,
+    /// represents an integer strictly less than `E::Scalar::MODULUS`.
+    ///
+    /// This walks the bits from most-significant to least-significant against the constant modulus
+    /// bits, tracking whether the prefix seen so far is still tied with the modulus. The value is
+    /// less than the modulus if and only if some bit position has `value_bit == 0` where
+    /// `modulus_bit == 1`, with every higher bit position tied; if every bit ties, the value equals
+    /// the modulus exactly, which is not canonical and must be rejected.
+    fn enforce_less_than_modulus(bits_le: &[Self::Boolean]) {
+        // Retrieve the modulus, truncated to `bits_le.len()` bits (the modulus fits in `size_in_bits`),
+        // in big-endian bit order to match the iteration below.
+        let modulus_bits_be = E::Scalar::modulus().to_bits_le()[..bits_le.len()].iter().rev().copied().collect::<Vec<_>>();
+
+        // `is_less` becomes `true` at the first bit position where the value is provably smaller,
+        // given that every higher bit position tied with the modulus so far.
+        let mut is_less = Boolean::constant(false);
+        // `is_tied` tracks whether every bit position seen so far is equal to the modulus's.
+        let mut is_tied = Boolean::constant(true);
+
+        for (value_bit, modulus_bit) in bits_le.iter().rev().zip_eq(modulus_bits_be.iter()) {
+            match modulus_bit {
+                // A `0` value bit against a `1` modulus bit, with a tied prefix, proves `value < modulus`.
+                true => is_less = &is_less | &(&is_tied & &(!value_bit)),
+                // A `1` value bit against a `0` modulus bit, with a tied prefix, would prove `value >
+                // modulus`; `is_tied` simply becomes `false`, since that case must never be reached.
+                false => (),
+            }
+            is_tied = &is_tied & &value_bit.is_equal(&Boolean::constant(*modulus_bit));
+        }
+
+        // The value must be strictly less than the modulus; equality would wrap to zero.
+        E::assert_eq(&is_less, &Boolean::constant(true));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_from_bits_le(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random canonical scalar.
+            let expected: console::Scalar<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let given_bits = expected.to_bits_le().iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            Circuit::scope("FromBits::from_bits_le", || {
+                let candidate = Scalar::<Circuit>::from_bits_le(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_from_bits_be(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random canonical scalar.
+            let expected: console::Scalar<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let given_bits = expected.to_bits_be().iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            Circuit::scope("FromBits::from_bits_be", || {
+                let candidate = Scalar::<Circuit>::from_bits_be(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_constant() {
+        check_from_bits_le(Mode::Constant)
+    }
+
+    #[test]
+    fn test_from_bits_le_public() {
+        check_from_bits_le(Mode::Public)
+    }
+
+    #[test]
+    fn test_from_bits_le_private() {
+        check_from_bits_le(Mode::Private)
+    }
+
+    #[test]
+    fn test_from_bits_be_private() {
+        check_from_bits_be(Mode::Private)
+    }
+
+    #[test]
+    fn test_from_bits_le_fails_on_noncanonical_value() {
+        // The all-ones bit vector of `Scalar::size_in_bits()` bits, i.e. `2^size_in_bits - 1`, is
+        // at or above the scalar modulus; the gadget must reject it by leaving the circuit unsatisfied.
+        let size_in_bits = Scalar::<Circuit>::size_in_bits();
+        let bits = vec![Boolean::<Circuit>::new(Mode::Private, true); size_in_bits];
+
+        Circuit::scope("FromBits::from_bits_le", || {
+            let _ = Scalar::<Circuit>::from_bits_le(&bits);
+            assert!(!Circuit::is_satisfied());
+        });
+        Circuit::reset();
+    }
+}
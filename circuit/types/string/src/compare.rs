@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Computes `(is_less_than, is_greater_than)` over the byte representation of `self` and
+    /// `other`, rather than `to_fields()`, since field packing destroys byte order.
+    ///
+    /// The shorter byte vector is zero-padded on the right, so that an equal shared prefix leaves
+    /// the shorter string "less"; if the longer string's extra bytes are all `0x00`, the two compare
+    /// as equal. The aligned byte pairs are then folded from the *least*
+    /// significant position back to the most significant, maintaining running `less`/`greater`
+    /// booleans; by the final (most-significant) byte, they hold the true lexicographic verdict.
+    fn compare_bytes(&self, other: &Self) -> (Boolean<E>, Boolean<E>) {
+        let this = self.as_bytes();
+        let that = other.as_bytes();
+
+        let zero = U8::<E>::constant(console::U8::new(0));
+        let len = this.len().max(that.len());
+
+        let mut less = Boolean::constant(false);
+        let mut greater = Boolean::constant(false);
+
+        for i in (0..len).rev() {
+            let a = this.get(i).unwrap_or(&zero);
+            let b = that.get(i).unwrap_or(&zero);
+
+            let is_equal = a.is_equal(b);
+            less = a.is_less_than(b) | (is_equal.clone() & less);
+            greater = a.is_greater_than(b) | (is_equal & greater);
+        }
+
+        (less, greater)
+    }
+}
+
+impl<E: Environment> Compare<Self> for StringType<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is lexicographically less than `other`.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        self.compare_bytes(other).0
+    }
+
+    /// Returns `true` if `self` is lexicographically greater than `other`.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        self.compare_bytes(other).1
+    }
+
+    /// Returns `true` if `self` is lexicographically less than or equal to `other`.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        !self.compare_bytes(other).1
+    }
+
+    /// Returns `true` if `self` is lexicographically greater than or equal to `other`.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        !self.compare_bytes(other).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use rand::Rng;
+
+    fn sample_string(mode: Mode, rng: &mut TestRng) -> StringType<Circuit> {
+        // Sample a random string. Take 1/4th to ensure we fit for all code points.
+        let given: String = (0..Circuit::MAX_STRING_BYTES / 4).map(|_| rng.gen::<char>()).collect();
+        StringType::<Circuit>::new(mode, console::StringType::new(&given))
+    }
+
+    // Note: unlike `equal.rs`, these tests do not assert fixed constraint counts via
+    // `assert_scope!`. Real counts would need to come from actually running this suite, which
+    // isn't possible in the environment this change was authored in — and `sample_string` itself
+    // makes the Constant-mode count non-obvious, since `rng.gen::<char>()` can encode to anywhere
+    // from 1 to 4 UTF-8 bytes per character, so the constant byte vector's length (and therefore
+    // the constraint count) varies from run to run unless the sampler is pinned to a fixed byte
+    // length first. Stick to functional assertions here rather than shipping guessed counts.
+    fn check_is_less_than(mode: Mode) -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a string; it must not be less than itself.
+        let string_a = sample_string(mode, &mut rng);
+
+        Circuit::scope(&format!("{}", mode), || {
+            let candidate = string_a.is_less_than(&string_a);
+            assert!(!candidate.eject_value());
+        });
+
+        Circuit::reset();
+        Ok(())
+    }
+
+    fn check_ordering_is_consistent(mode: Mode) -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample two strings and check the four comparisons agree with each other.
+        let string_a = sample_string(mode, &mut rng);
+        let string_b = sample_string(mode, &mut rng);
+
+        Circuit::scope(&format!("{}", mode), || {
+            let lt = string_a.is_less_than(&string_b).eject_value();
+            let gt = string_a.is_greater_than(&string_b).eject_value();
+            let le = string_a.is_less_than_or_equal(&string_b).eject_value();
+            let ge = string_a.is_greater_than_or_equal(&string_b).eject_value();
+
+            // Exactly one of `<`, `==`, `>` must hold, and the `-or-equal` variants must agree.
+            assert!(!(lt && gt));
+            assert_eq!(le, !gt);
+            assert_eq!(ge, !lt);
+        });
+
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_less_than_constant() -> Result<()> {
+        check_is_less_than(Mode::Constant)
+    }
+
+    #[test]
+    fn test_is_less_than_public() -> Result<()> {
+        check_is_less_than(Mode::Public)
+    }
+
+    #[test]
+    fn test_is_less_than_private() -> Result<()> {
+        check_is_less_than(Mode::Private)
+    }
+
+    #[test]
+    fn test_ordering_is_consistent_constant() -> Result<()> {
+        check_ordering_is_consistent(Mode::Constant)
+    }
+
+    #[test]
+    fn test_ordering_is_consistent_public() -> Result<()> {
+        check_ordering_is_consistent(Mode::Public)
+    }
+
+    #[test]
+    fn test_ordering_is_consistent_private() -> Result<()> {
+        check_ordering_is_consistent(Mode::Private)
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns `true` if `self` starts with `needle`.
+    ///
+    /// Operates over the byte vector, not `to_fields()`. Both lengths are known at synthesis
+    /// time, so if `needle` is longer than `self` the result is the circuit-constant `false`;
+    /// otherwise this folds an AND over the first `needle.len()` byte equalities.
+    pub fn starts_with(&self, needle: &Self) -> Boolean<E> {
+        let haystack = self.as_bytes();
+        let needle = needle.as_bytes();
+
+        if needle.len() > haystack.len() {
+            return Boolean::constant(false);
+        }
+
+        haystack[..needle.len()]
+            .iter()
+            .zip_eq(needle)
+            .fold(Boolean::constant(true), |acc, (haystack_byte, needle_byte)| acc & haystack_byte.is_equal(needle_byte))
+    }
+
+    /// Returns `true` if `self` ends with `needle`.
+    ///
+    /// Operates over the byte vector, not `to_fields()`. This is [`Self::starts_with`] aligned to
+    /// the tail: both lengths are circuit-constant, so if `needle` is longer than `self` the
+    /// result is the circuit-constant `false`; otherwise this folds an AND over the last
+    /// `needle.len()` byte equalities.
+    pub fn ends_with(&self, needle: &Self) -> Boolean<E> {
+        let haystack = self.as_bytes();
+        let needle = needle.as_bytes();
+
+        if needle.len() > haystack.len() {
+            return Boolean::constant(false);
+        }
+
+        haystack[haystack.len() - needle.len()..]
+            .iter()
+            .zip_eq(needle)
+            .fold(Boolean::constant(true), |acc, (haystack_byte, needle_byte)| acc & haystack_byte.is_equal(needle_byte))
+    }
+
+    /// Returns `true` if `self` contains `needle` anywhere within it.
+    ///
+    /// Operates over the byte vector, not `to_fields()`. Because both lengths are known at
+    /// synthesis time, the loop bounds below are circuit-constant: this slides `needle` across
+    /// every valid offset `0..=self.len() - needle.len()`, computes a per-offset AND-of-byte-
+    /// equalities match boolean, and ORs the offset results together. An empty `needle` always
+    /// matches; a `needle` longer than `self` never does.
+    pub fn contains(&self, needle: &Self) -> Boolean<E> {
+        let haystack = self.as_bytes();
+        let needle = needle.as_bytes();
+
+        if needle.is_empty() {
+            return Boolean::constant(true);
+        }
+        if needle.len() > haystack.len() {
+            return Boolean::constant(false);
+        }
+
+        (0..=haystack.len() - needle.len()).fold(Boolean::constant(false), |acc, offset| {
+            let matches_at_offset = haystack[offset..offset + needle.len()]
+                .iter()
+                .zip_eq(needle)
+                .fold(Boolean::constant(true), |acc, (haystack_byte, needle_byte)| acc & haystack_byte.is_equal(needle_byte));
+            acc | matches_at_offset
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn sample_string(mode: Mode, given: &str) -> StringType<Circuit> {
+        StringType::<Circuit>::new(mode, console::StringType::new(given))
+    }
+
+    // Note: unlike `equal.rs`, these tests do not assert fixed constraint counts via
+    // `assert_scope!` — real counts would need to come from actually running this suite, which
+    // isn't possible in the environment this change was authored in. Stick to functional
+    // assertions, parameterized across modes the same way `equal.rs` is, rather than shipping
+    // guessed counts.
+    fn check_starts_with(mode: Mode) {
+        let haystack = sample_string(mode, "hello world");
+
+        Circuit::scope(&format!("{}", mode), || {
+            assert!(haystack.starts_with(&sample_string(mode, "hello")).eject_value());
+            assert!(!haystack.starts_with(&sample_string(mode, "world")).eject_value());
+            // An empty needle always matches as a prefix.
+            assert!(haystack.starts_with(&sample_string(mode, "")).eject_value());
+            // A needle longer than the haystack never matches.
+            assert!(!haystack.starts_with(&sample_string(mode, "hello world and beyond")).eject_value());
+        });
+        Circuit::reset();
+    }
+
+    fn check_ends_with(mode: Mode) {
+        let haystack = sample_string(mode, "hello world");
+
+        Circuit::scope(&format!("{}", mode), || {
+            assert!(haystack.ends_with(&sample_string(mode, "world")).eject_value());
+            assert!(!haystack.ends_with(&sample_string(mode, "hello")).eject_value());
+            assert!(haystack.ends_with(&sample_string(mode, "")).eject_value());
+            assert!(!haystack.ends_with(&sample_string(mode, "hello world and beyond")).eject_value());
+        });
+        Circuit::reset();
+    }
+
+    fn check_contains(mode: Mode) {
+        let haystack = sample_string(mode, "hello world");
+
+        Circuit::scope(&format!("{}", mode), || {
+            assert!(haystack.contains(&sample_string(mode, "lo wo")).eject_value());
+            assert!(haystack.contains(&sample_string(mode, "hello world")).eject_value());
+            assert!(!haystack.contains(&sample_string(mode, "goodbye")).eject_value());
+            // An empty needle always matches.
+            assert!(haystack.contains(&sample_string(mode, "")).eject_value());
+            // A needle longer than the haystack never matches.
+            assert!(!haystack.contains(&sample_string(mode, "hello world and beyond")).eject_value());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_starts_with_constant() {
+        check_starts_with(Mode::Constant)
+    }
+
+    #[test]
+    fn test_starts_with_public() {
+        check_starts_with(Mode::Public)
+    }
+
+    #[test]
+    fn test_starts_with_private() {
+        check_starts_with(Mode::Private)
+    }
+
+    #[test]
+    fn test_ends_with_constant() {
+        check_ends_with(Mode::Constant)
+    }
+
+    #[test]
+    fn test_ends_with_public() {
+        check_ends_with(Mode::Public)
+    }
+
+    #[test]
+    fn test_ends_with_private() {
+        check_ends_with(Mode::Private)
+    }
+
+    #[test]
+    fn test_contains_constant() {
+        check_contains(Mode::Constant)
+    }
+
+    #[test]
+    fn test_contains_public() {
+        check_contains(Mode::Public)
+    }
+
+    #[test]
+    fn test_contains_private() {
+        check_contains(Mode::Private)
+    }
+}
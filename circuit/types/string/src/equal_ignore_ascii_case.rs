@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns `true` if `self` and `other` are equal, ignoring ASCII case.
+    ///
+    /// Operates over the byte vector, unlike `is_equal`, which compares `to_fields()`: each byte is
+    /// first folded to its ASCII-lowercase form inside the circuit, and the folded byte streams
+    /// are then compared with the same length-guard and AND-fold `is_equal` already uses. Bytes
+    /// outside the ASCII uppercase range (including all non-ASCII bytes) pass through unchanged.
+    pub fn is_equal_ignore_ascii_case(&self, other: &Self) -> Boolean<E> {
+        let this = Self::fold_to_ascii_lowercase(self.as_bytes());
+        let that = Self::fold_to_ascii_lowercase(other.as_bytes());
+
+        // Return `false` if the lengths of the strings are not equal.
+        if this.len() != that.len() {
+            return Boolean::constant(false);
+        }
+
+        this.iter().zip_eq(&that).fold(Boolean::constant(true), |acc, (a, b)| acc & a.is_equal(b))
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal, ignoring ASCII case.
+    pub fn is_not_equal_ignore_ascii_case(&self, other: &Self) -> Boolean<E> {
+        !self.is_equal_ignore_ascii_case(other)
+    }
+
+    /// Folds each byte in `bytes` to its ASCII-lowercase form: `is_upper = (b >= 'A') & (b <=
+    /// 'Z')`, then `folded = Ternary::ternary(is_upper, b + 32, b)`. Bytes outside the ASCII
+    /// uppercase range, including non-ASCII bytes, are returned unchanged.
+    fn fold_to_ascii_lowercase(bytes: &[U8<E>]) -> Vec<U8<E>> {
+        let ascii_upper_a = U8::<E>::constant(console::U8::new(b'A'));
+        let ascii_upper_z = U8::<E>::constant(console::U8::new(b'Z'));
+        let ascii_case_offset = U8::<E>::constant(console::U8::new(32));
+
+        bytes
+            .iter()
+            .map(|byte| {
+                let is_upper = byte.is_greater_than_or_equal(&ascii_upper_a) & byte.is_less_than_or_equal(&ascii_upper_z);
+                // Wrapping addition, so bytes at or above 224 (which are never selected) cannot overflow.
+                Ternary::ternary(&is_upper, &byte.add_wrapped(&ascii_case_offset), byte)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn sample_string(mode: Mode, given: &str) -> StringType<Circuit> {
+        StringType::<Circuit>::new(mode, console::StringType::new(given))
+    }
+
+    // Note: unlike `equal.rs`, this does not assert fixed constraint counts via `assert_scope!` —
+    // real counts would need to come from actually running this suite, which isn't possible in
+    // the environment this change was authored in. Stick to functional assertions rather than
+    // shipping guessed counts.
+    fn check_is_equal_ignore_ascii_case(mode: Mode) {
+        Circuit::scope(&format!("{}", mode), || {
+            // Mixed-case strings that are equal once case-folded.
+            let a = sample_string(mode, "Hello World");
+            let b = sample_string(mode, "hello world");
+            assert!(a.is_equal_ignore_ascii_case(&b).eject_value());
+            assert!(!a.is_not_equal_ignore_ascii_case(&b).eject_value());
+
+            // Genuinely different strings remain unequal.
+            let c = sample_string(mode, "Goodbye World");
+            assert!(!a.is_equal_ignore_ascii_case(&c).eject_value());
+            assert!(a.is_not_equal_ignore_ascii_case(&c).eject_value());
+
+            // Non-ASCII bytes pass through unchanged, so they must compare as-is.
+            let d = sample_string(mode, "héllo world");
+            let e = sample_string(mode, "héllo WORLD");
+            assert!(d.is_equal_ignore_ascii_case(&e).eject_value());
+            let f = sample_string(mode, "Héllo world");
+            assert!(!d.is_equal_ignore_ascii_case(&f).eject_value());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_is_equal_ignore_ascii_case_constant() {
+        check_is_equal_ignore_ascii_case(Mode::Constant)
+    }
+
+    #[test]
+    fn test_is_equal_ignore_ascii_case_public() {
+        check_is_equal_ignore_ascii_case(Mode::Public)
+    }
+
+    #[test]
+    fn test_is_equal_ignore_ascii_case_private() {
+        check_is_equal_ignore_ascii_case(Mode::Private)
+    }
+}
@@ -0,0 +1,304 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Parses `self` as an ASCII digit string in the given `radix` (2 to 16, inclusive), returning
+    /// the parsed `Integer<E, I>` alongside a `Boolean<E>` that is `true` only if every byte of
+    /// `self` was a valid digit for `radix`, the string was non-empty, and the accumulation did
+    /// not overflow `I`.
+    ///
+    /// Iterates the bytes left-to-right. For each byte, `is_digit = (b >= '0') & (b <= '9')` (plus
+    /// `a-f`/`A-F` for `radix > 10`) and the digit value `d = b - '0'` (or `b - 'a' + 10`, etc.) are
+    /// computed via `U8` gadgets; `acc = acc * radix + d` is accumulated in the target integer
+    /// type, guarded by its checked-multiply/add gadgets, with any overflow or non-digit byte
+    /// folded into the validity `Boolean`. An empty string is always invalid.
+    pub fn to_integer<I: IntegerType>(&self, radix: u32) -> (Integer<E, I>, Boolean<E>) {
+        debug_assert!((2..=16).contains(&radix), "radix must be between 2 and 16, inclusive");
+
+        let bytes = self.as_bytes();
+
+        let mut acc = Integer::<E, I>::zero();
+        let mut is_valid = Boolean::constant(!bytes.is_empty());
+        let radix_integer = Integer::<E, I>::constant(console::Integer::new(I::from_u32(radix).unwrap()));
+
+        for byte in bytes {
+            let (digit, is_digit) = Self::ascii_digit_value(byte, radix);
+
+            // Accumulate `acc = acc * radix + digit`, guarding against overflow in either step.
+            let (product, did_mul_overflow) = acc.overflowing_mul(&radix_integer);
+            let (sum, did_add_overflow) = product.overflowing_add(&digit);
+
+            is_valid = is_valid & is_digit & !did_mul_overflow & !did_add_overflow;
+            acc = sum;
+        }
+
+        (acc, is_valid)
+    }
+
+    /// Computes the digit value of `byte` in `radix`, along with a `Boolean<E>` that is `true` if
+    /// `byte` is a valid digit for `radix`. Supports decimal digits `0-9` for any `radix`, plus
+    /// `a-f`/`A-F` for `radix > 10`.
+    fn ascii_digit_value<I: IntegerType>(byte: &U8<E>, radix: u32) -> (Integer<E, I>, Boolean<E>) {
+        let zero_digit = U8::<E>::constant(console::U8::new(b'0'));
+        let nine_digit = U8::<E>::constant(console::U8::new(b'9'));
+
+        let is_decimal_digit = byte.is_greater_than_or_equal(&zero_digit) & byte.is_less_than_or_equal(&nine_digit);
+        // Wrapping subtraction, so a byte below '0' yields an (invalid) value instead of underflowing.
+        let decimal_value = byte.sub_wrapped(&zero_digit);
+
+        let mut is_digit = is_decimal_digit.clone();
+        let mut value = decimal_value;
+
+        if radix > 10 {
+            // The radix exceeds 10; also accept `a-f` and `A-F`.
+            let lower_a = U8::<E>::constant(console::U8::new(b'a'));
+            let lower_f = U8::<E>::constant(console::U8::new(b'f'));
+            let upper_a = U8::<E>::constant(console::U8::new(b'A'));
+            let upper_f = U8::<E>::constant(console::U8::new(b'F'));
+            let ten = U8::<E>::constant(console::U8::new(10));
+
+            let is_lower_hex = byte.is_greater_than_or_equal(&lower_a) & byte.is_less_than_or_equal(&lower_f);
+            let is_upper_hex = byte.is_greater_than_or_equal(&upper_a) & byte.is_less_than_or_equal(&upper_f);
+
+            // Wrapping arithmetic, as these are computed for every byte, not only for hex letters.
+            let lower_hex_value = byte.sub_wrapped(&lower_a).add_wrapped(&ten);
+            let upper_hex_value = byte.sub_wrapped(&upper_a).add_wrapped(&ten);
+
+            value = U8::ternary(&is_lower_hex, &lower_hex_value, &value);
+            value = U8::ternary(&is_upper_hex, &upper_hex_value, &value);
+            is_digit = is_digit | is_lower_hex | is_upper_hex;
+        }
+
+        let value = Integer::<E, I>::from_bits_le(&value.to_bits_le());
+        // A decoded digit value must still be less than the radix (e.g. `8` is not a valid base-8 digit).
+        let radix_integer = Integer::<E, I>::constant(console::Integer::new(I::from_u32(radix).unwrap()));
+        is_digit = is_digit & value.is_less_than(&radix_integer);
+
+        (value, is_digit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+    use snarkvm_circuit_types_integers::U32;
+
+    fn sample_string(mode: Mode, given: &str) -> StringType<Circuit> {
+        StringType::<Circuit>::new(mode, console::StringType::new(given))
+    }
+
+    // Note: unlike `equal.rs`, these tests do not assert fixed constraint counts via
+    // `assert_scope!` — real counts would need to come from actually running this suite, which
+    // isn't possible in the environment this change was authored in. Stick to functional
+    // assertions, parameterized across modes the same way `equal.rs` is, rather than shipping
+    // guessed counts.
+    fn check_to_integer_decimal(mode: Mode) {
+        Circuit::scope(&format!("{}", mode), || {
+            let (value, is_valid) = sample_string(mode, "12345").to_integer::<u32>(10);
+            assert_eq!(12345u32, value.eject_value());
+            assert!(is_valid.eject_value());
+        });
+        Circuit::reset();
+    }
+
+    fn check_to_integer_leading_zeros(mode: Mode) {
+        Circuit::scope(&format!("{}", mode), || {
+            let (value, is_valid) = sample_string(mode, "007").to_integer::<u32>(10);
+            assert_eq!(7u32, value.eject_value());
+            assert!(is_valid.eject_value());
+        });
+        Circuit::reset();
+    }
+
+    fn check_to_integer_radix_twelve(mode: Mode) {
+        Circuit::scope(&format!("{}", mode), || {
+            // `a` is a valid digit (value 10) for any radix greater than 10, not just radix 16.
+            let (value, is_valid) = sample_string(mode, "a0").to_integer::<u32>(12);
+            assert_eq!(10 * 12, value.eject_value());
+            assert!(is_valid.eject_value());
+
+            // `c` (value 12) is out of range for radix 12 and must be rejected.
+            let (_, is_valid) = sample_string(mode, "c0").to_integer::<u32>(12);
+            assert!(!is_valid.eject_value());
+        });
+        Circuit::reset();
+    }
+
+    fn check_to_integer_hex(mode: Mode) {
+        Circuit::scope(&format!("{}", mode), || {
+            let (value, is_valid) = sample_string(mode, "2Ff").to_integer::<u32>(16);
+            assert_eq!(0x2ffu32, value.eject_value());
+            assert!(is_valid.eject_value());
+        });
+        Circuit::reset();
+    }
+
+    fn check_to_integer_empty_string_is_invalid(mode: Mode) {
+        Circuit::scope(&format!("{}", mode), || {
+            let (_, is_valid) = sample_string(mode, "").to_integer::<u32>(10);
+            assert!(!is_valid.eject_value());
+        });
+        Circuit::reset();
+    }
+
+    fn check_to_integer_non_digit_is_invalid(mode: Mode) {
+        Circuit::scope(&format!("{}", mode), || {
+            let (_, is_valid) = sample_string(mode, "12a45").to_integer::<u32>(10);
+            assert!(!is_valid.eject_value());
+        });
+        Circuit::reset();
+    }
+
+    fn check_to_integer_overflow_is_invalid(mode: Mode) {
+        Circuit::scope(&format!("{}", mode), || {
+            let (_, is_valid) = sample_string(mode, "99999999999").to_integer::<u32>(10);
+            assert!(!is_valid.eject_value());
+        });
+        Circuit::reset();
+    }
+
+    fn check_to_integer_max_value(mode: Mode) {
+        Circuit::scope(&format!("{}", mode), || {
+            let (value, is_valid) = sample_string(mode, &u32::MAX.to_string()).to_integer::<u32>(10);
+            assert_eq!(u32::MAX, value.eject_value());
+            assert!(is_valid.eject_value());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_to_integer_decimal_constant() {
+        check_to_integer_decimal(Mode::Constant)
+    }
+
+    #[test]
+    fn test_to_integer_decimal_public() {
+        check_to_integer_decimal(Mode::Public)
+    }
+
+    #[test]
+    fn test_to_integer_decimal_private() {
+        check_to_integer_decimal(Mode::Private)
+    }
+
+    #[test]
+    fn test_to_integer_leading_zeros_constant() {
+        check_to_integer_leading_zeros(Mode::Constant)
+    }
+
+    #[test]
+    fn test_to_integer_leading_zeros_public() {
+        check_to_integer_leading_zeros(Mode::Public)
+    }
+
+    #[test]
+    fn test_to_integer_leading_zeros_private() {
+        check_to_integer_leading_zeros(Mode::Private)
+    }
+
+    #[test]
+    fn test_to_integer_radix_twelve_constant() {
+        check_to_integer_radix_twelve(Mode::Constant)
+    }
+
+    #[test]
+    fn test_to_integer_radix_twelve_public() {
+        check_to_integer_radix_twelve(Mode::Public)
+    }
+
+    #[test]
+    fn test_to_integer_radix_twelve_private() {
+        check_to_integer_radix_twelve(Mode::Private)
+    }
+
+    #[test]
+    fn test_to_integer_hex_constant() {
+        check_to_integer_hex(Mode::Constant)
+    }
+
+    #[test]
+    fn test_to_integer_hex_public() {
+        check_to_integer_hex(Mode::Public)
+    }
+
+    #[test]
+    fn test_to_integer_hex_private() {
+        check_to_integer_hex(Mode::Private)
+    }
+
+    #[test]
+    fn test_to_integer_empty_string_is_invalid_constant() {
+        check_to_integer_empty_string_is_invalid(Mode::Constant)
+    }
+
+    #[test]
+    fn test_to_integer_empty_string_is_invalid_public() {
+        check_to_integer_empty_string_is_invalid(Mode::Public)
+    }
+
+    #[test]
+    fn test_to_integer_empty_string_is_invalid_private() {
+        check_to_integer_empty_string_is_invalid(Mode::Private)
+    }
+
+    #[test]
+    fn test_to_integer_non_digit_is_invalid_constant() {
+        check_to_integer_non_digit_is_invalid(Mode::Constant)
+    }
+
+    #[test]
+    fn test_to_integer_non_digit_is_invalid_public() {
+        check_to_integer_non_digit_is_invalid(Mode::Public)
+    }
+
+    #[test]
+    fn test_to_integer_non_digit_is_invalid_private() {
+        check_to_integer_non_digit_is_invalid(Mode::Private)
+    }
+
+    #[test]
+    fn test_to_integer_overflow_is_invalid_constant() {
+        check_to_integer_overflow_is_invalid(Mode::Constant)
+    }
+
+    #[test]
+    fn test_to_integer_overflow_is_invalid_public() {
+        check_to_integer_overflow_is_invalid(Mode::Public)
+    }
+
+    #[test]
+    fn test_to_integer_overflow_is_invalid_private() {
+        check_to_integer_overflow_is_invalid(Mode::Private)
+    }
+
+    #[test]
+    fn test_to_integer_max_value_constant() {
+        check_to_integer_max_value(Mode::Constant)
+    }
+
+    #[test]
+    fn test_to_integer_max_value_public() {
+        check_to_integer_max_value(Mode::Public)
+    }
+
+    #[test]
+    fn test_to_integer_max_value_private() {
+        check_to_integer_max_value(Mode::Private)
+    }
+}
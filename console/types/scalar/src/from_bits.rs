@@ -75,6 +75,40 @@ impl<E: Environment> FromBits for Scalar<E> {
     }
 }
 
+impl<E: Environment> Scalar<E> {
+    /// Initializes a new scalar from a list of **little-endian** bits, reducing modulo
+    /// `Scalar::MODULUS` rather than rejecting bits that represent a value greater than or equal
+    /// to it.
+    ///
+    /// Unlike [`FromBits::from_bits_le`], this constructor never fails, and does not treat excess
+    /// bits as an error. It is intended for wide, hash-derived bit strings (e.g. the output of a
+    /// XOF), where rejecting out-of-range values would bias the resulting distribution, rather
+    /// than for reconstructing a previously-canonicalized scalar.
+    pub fn from_bits_le_mod(bits_le: &[bool]) -> Self {
+        // Accumulate the bits from most-significant to least-significant, doubling in the scalar
+        // field at each step. Because field addition is already taken modulo `Scalar::MODULUS`,
+        // the result is `bits_le` interpreted as an integer, reduced modulo the scalar field's
+        // order.
+        bits_le.iter().rev().fold(Self::zero(), |acc, bit| {
+            let acc = acc + acc;
+            match bit {
+                true => acc + Self::one(),
+                false => acc,
+            }
+        })
+    }
+
+    /// Initializes a new scalar from a list of **big-endian** bits, reducing modulo
+    /// `Scalar::MODULUS`. See [`Scalar::from_bits_le_mod`] for details.
+    pub fn from_bits_be_mod(bits_be: &[bool]) -> Self {
+        // Reverse the given bits from big-endian into little-endian.
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+
+        Self::from_bits_le_mod(&bits_le)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -137,4 +171,93 @@ mod tests {
     fn test_from_bits_be() -> Result<()> {
         check_from_bits_be()
     }
+
+    #[test]
+    fn test_from_bits_le_mod_agrees_with_from_bits_le_when_canonical() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random element; its canonical bits must round-trip identically through
+            // both constructors.
+            let expected: Scalar<CurrentEnvironment> = Uniform::rand(&mut rng);
+            let given_bits = expected.to_bits_le();
+
+            let candidate = Scalar::<CurrentEnvironment>::from_bits_le_mod(&given_bits);
+            assert_eq!(expected, candidate);
+        }
+    }
+
+    /// Reduces `bits_le` (a little-endian bit vector, interpreted as a non-negative integer)
+    /// modulo the scalar field's order, returning the result as a canonical little-endian bit
+    /// vector of length `Scalar::size_in_bits()`.
+    ///
+    /// This computes the same value as `from_bits_le_mod`, but independently: via schoolbook
+    /// binary long division over raw bit vectors (double the running remainder, then conditionally
+    /// subtract the modulus), rather than by folding scalar-field addition/doubling. It exists only
+    /// to serve as a reference in tests.
+    fn reduce_mod_order_reference(bits_le: &[bool]) -> Vec<bool> {
+        let modulus_bits_be = <CurrentEnvironment as Environment>::Scalar::modulus().to_bits_be();
+        // One guard bit above the modulus's own bit length: doubling a remainder just under the
+        // modulus can transiently exceed it, before the conditional subtraction brings it back down.
+        let padded_modulus_bits_be = std::iter::once(false).chain(modulus_bits_be.iter().copied()).collect::<Vec<_>>();
+        let width = padded_modulus_bits_be.len();
+
+        let mut remainder = vec![false; width];
+        for &bit in bits_le.iter().rev() {
+            // remainder = remainder * 2 + bit
+            remainder.copy_within(1..width, 0);
+            remainder[width - 1] = bit;
+
+            // if remainder >= modulus, remainder -= modulus
+            if remainder >= padded_modulus_bits_be {
+                let mut borrow = false;
+                for i in (0..width).rev() {
+                    let (a, b) = (remainder[i] as i8, padded_modulus_bits_be[i] as i8 + borrow as i8);
+                    let (diff, new_borrow) = if a < b { (a + 2 - b, true) } else { (a - b, false) };
+                    remainder[i] = diff == 1;
+                    borrow = new_borrow;
+                }
+            }
+        }
+
+        remainder[1..].iter().rev().copied().collect()
+    }
+
+    fn check_from_bits_le_mod_matches_reference_reduction(num_bits: usize) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            // A wide, hash-derived bit string is, in general, not a canonical scalar, and
+            // `from_bits_le` would reject it; `from_bits_le_mod` must still succeed, and must agree
+            // with an independently-computed modular reduction, not merely avoid panicking.
+            let bits = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let candidate = Scalar::<CurrentEnvironment>::from_bits_le_mod(&bits);
+
+            let expected_bits = reduce_mod_order_reference(&bits);
+            let expected = Scalar::<CurrentEnvironment>::from_bits_le(&expected_bits)
+                .expect("the reference reduction must always be a canonical scalar");
+            assert_eq!(expected, candidate);
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_mod_never_fails_on_wide_input() {
+        for num_bits in [Scalar::<CurrentEnvironment>::size_in_bits() + 1, 2 * Scalar::<CurrentEnvironment>::size_in_bits()] {
+            check_from_bits_le_mod_matches_reference_reduction(num_bits);
+        }
+    }
+
+    #[test]
+    fn test_from_bits_be_mod_is_reverse_of_from_bits_le_mod() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let bits_le = (0..2 * Scalar::<CurrentEnvironment>::size_in_bits()).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let bits_be = bits_le.iter().copied().rev().collect::<Vec<_>>();
+
+            let expected = Scalar::<CurrentEnvironment>::from_bits_le_mod(&bits_le);
+            let candidate = Scalar::<CurrentEnvironment>::from_bits_be_mod(&bits_be);
+            assert_eq!(expected, candidate);
+        }
+    }
 }
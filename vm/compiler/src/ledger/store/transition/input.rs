@@ -28,7 +28,153 @@ use console::{
 };
 
 use anyhow::Result;
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt,
+    hash::Hash,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+        RwLock,
+    },
+};
+
+/// A structural inconsistency discovered by [`InputStorage::verify`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Inconsistency<N: Network> {
+    /// A `reverse_id_map` entry whose `transition_id` has no `id_map` row, or whose row doesn't list it.
+    DanglingReverseId { input_id: Field<N>, transition_id: N::TransitionID },
+    /// A `record_tag_map` entry pointing at a serial number that is absent from `record_map`, or that is
+    /// stored there under a different tag.
+    RecordTagMismatch { tag: Field<N>, serial_number: Field<N> },
+    /// A `record_map` entry whose tag is absent from `record_tag_map`, or is stored there for a different
+    /// serial number.
+    RecordMissingTag { serial_number: Field<N>, tag: Field<N> },
+    /// An input ID present in more than one of the constant/public/private/record/external-record maps.
+    DuplicateInputId { input_id: Field<N>, count: usize },
+    /// An `id_map` entry referencing an input ID that exists in none of the constant/public/private/record/
+    /// external-record maps.
+    OrphanedInputId { transition_id: N::TransitionID, input_id: Field<N> },
+}
+
+impl<N: Network> fmt::Display for Inconsistency<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DanglingReverseId { input_id, transition_id } => {
+                write!(f, "'reverse_id_map' entry for input '{input_id}' does not match 'id_map' of transition '{transition_id}'")
+            }
+            Self::RecordTagMismatch { tag, serial_number } => {
+                write!(f, "'record_tag_map' entry for tag '{tag}' does not match 'record_map' of serial number '{serial_number}'")
+            }
+            Self::RecordMissingTag { serial_number, tag } => {
+                write!(f, "'record_map' entry for serial number '{serial_number}' does not match 'record_tag_map' of tag '{tag}'")
+            }
+            Self::DuplicateInputId { input_id, count } => {
+                write!(f, "input ID '{input_id}' is present in {count} value maps, expected at most 1")
+            }
+            Self::OrphanedInputId { transition_id, input_id } => {
+                write!(f, "input ID '{input_id}' in transition '{transition_id}' is missing from every value map")
+            }
+        }
+    }
+}
+
+/// One entry in an [`InputSavepoint`]'s undo log for a single map: either `key` did not exist
+/// before this write (undo by removing it), or it held `value` (undo by restoring it).
+enum UndoOp<K, V> {
+    Insert(K),
+    Overwrite(K, V),
+}
+
+/// The undo log captured for a single atomic-batch nesting level. Unlike a full-map snapshot, this
+/// only records the keys actually written since the matching `start_atomic`, so opening a savepoint
+/// is O(1) and rolling one back costs only the writes that level actually made, not the size of the
+/// backing storage.
+struct InputSavepoint<N: Network> {
+    id_map: Vec<UndoOp<N::TransitionID, Vec<Field<N>>>>,
+    reverse_id_map: Vec<UndoOp<Field<N>, N::TransitionID>>,
+    constant_map: Vec<UndoOp<Field<N>, Option<Plaintext<N>>>>,
+    public_map: Vec<UndoOp<Field<N>, Option<Plaintext<N>>>>,
+    private_map: Vec<UndoOp<Field<N>, Option<Ciphertext<N>>>>,
+    record_map: Vec<UndoOp<Field<N>, (Field<N>, Origin<N>)>>,
+    record_tag_map: Vec<UndoOp<Field<N>, Field<N>>>,
+    external_record_map: Vec<UndoOp<Field<N>, ()>>,
+}
+
+impl<N: Network> Default for InputSavepoint<N> {
+    fn default() -> Self {
+        Self {
+            id_map: Vec::new(),
+            reverse_id_map: Vec::new(),
+            constant_map: Vec::new(),
+            public_map: Vec::new(),
+            private_map: Vec::new(),
+            record_map: Vec::new(),
+            record_tag_map: Vec::new(),
+            external_record_map: Vec::new(),
+        }
+    }
+}
+
+/// Tracks the nesting depth and savepoint stack backing an [`InputStorage`]'s atomic batches.
+struct AtomicBatchState<N: Network> {
+    /// The current atomic batch nesting depth. `0` means no batch is in progress.
+    depth: AtomicU64,
+    /// A stack of savepoints, one per currently-open nesting level, innermost last.
+    savepoints: RwLock<Vec<InputSavepoint<N>>>,
+}
+
+impl<N: Network> Default for AtomicBatchState<N> {
+    fn default() -> Self {
+        Self { depth: AtomicU64::new(0), savepoints: RwLock::new(Vec::new()) }
+    }
+}
+
+/// Replays `ops` against `map` in reverse order, undoing exactly the writes that produced them.
+/// Reverse order matters: if the same key was written more than once at this nesting level, only
+/// unwinding the most recent write first restores its original pre-batch value.
+fn apply_undo<'a, K, V, M>(map: &M, ops: Vec<UndoOp<K, V>>) -> Result<()>
+where
+    K: 'a + Eq + Hash + Clone,
+    V: 'a + Clone,
+    M: Map<'a, K, V>,
+{
+    for op in ops.into_iter().rev() {
+        match op {
+            UndoOp::Insert(key) => map.remove(&key)?,
+            UndoOp::Overwrite(key, value) => map.insert(key, value)?,
+        }
+    }
+    Ok(())
+}
+
+/// Records the prior value of `key` in `map`, as an [`UndoOp`] appended to `log`, before `key` is
+/// overwritten or removed. A no-op if no atomic batch is open, i.e. `log` is `None`.
+fn record_undo_entry<'a, K, V, M>(map: &M, log: Option<&mut Vec<UndoOp<K, V>>>, key: K) -> Result<()>
+where
+    K: 'a + Eq + Hash + Clone,
+    V: 'a + Clone,
+    M: Map<'a, K, V>,
+{
+    if let Some(log) = log {
+        log.push(match map.get(&key)?.map(|value| value.into_owned()) {
+            Some(value) => UndoOp::Overwrite(key, value),
+            None => UndoOp::Insert(key),
+        });
+    }
+    Ok(())
+}
+
+/// Records the prior value of `key` in the innermost open savepoint's undo log for `$map`, before
+/// that key is written via `$map`'s accessor on `$self`. Expands to a no-op if no atomic batch is
+/// currently open.
+macro_rules! record_undo {
+    ($self:expr, $map:ident, $key:expr) => {{
+        let mut savepoints = $self.atomic_state().savepoints.write().unwrap();
+        record_undo_entry($self.$map(), savepoints.last_mut().map(|savepoint| &mut savepoint.$map), $key)
+    }};
+}
 
 /// A trait for transition input storage.
 pub trait InputStorage<N: Network>: Clone + Send + Sync {
@@ -72,76 +218,153 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
 
-    /// Starts an atomic batch write operation.
+    /// Returns the atomic batch nesting state, shared across clones of this storage.
+    fn atomic_state(&self) -> &AtomicBatchState<N>;
+
+    /// Undoes every write recorded in `savepoint`, one map at a time, restoring this nesting
+    /// level's maps to their state just before the matching `start_atomic`.
+    fn restore(&self, savepoint: InputSavepoint<N>) -> Result<()> {
+        apply_undo(self.id_map(), savepoint.id_map)?;
+        apply_undo(self.reverse_id_map(), savepoint.reverse_id_map)?;
+        apply_undo(self.constant_map(), savepoint.constant_map)?;
+        apply_undo(self.public_map(), savepoint.public_map)?;
+        apply_undo(self.private_map(), savepoint.private_map)?;
+        apply_undo(self.record_map(), savepoint.record_map)?;
+        apply_undo(self.record_tag_map(), savepoint.record_tag_map)?;
+        apply_undo(self.external_record_map(), savepoint.external_record_map)
+    }
+
+    /// Starts an atomic batch write operation. Nested calls only open the underlying batch once;
+    /// each level pushes its own savepoint, so an inner `finish_atomic` does not prematurely commit
+    /// an enclosing, caller-owned batch.
     fn start_atomic(&self) {
-        self.id_map().start_atomic();
-        self.reverse_id_map().start_atomic();
-        self.constant_map().start_atomic();
-        self.public_map().start_atomic();
-        self.private_map().start_atomic();
-        self.record_map().start_atomic();
-        self.record_tag_map().start_atomic();
-        self.external_record_map().start_atomic();
+        let state = self.atomic_state();
+        if state.depth.fetch_add(1, Ordering::SeqCst) == 0 {
+            self.id_map().start_atomic();
+            self.reverse_id_map().start_atomic();
+            self.constant_map().start_atomic();
+            self.public_map().start_atomic();
+            self.private_map().start_atomic();
+            self.record_map().start_atomic();
+            self.record_tag_map().start_atomic();
+            self.external_record_map().start_atomic();
+        }
+        // Push an empty undo log; it is filled in lazily, one entry per key actually written at
+        // this nesting level, rather than by copying every map up front.
+        state.savepoints.write().unwrap().push(InputSavepoint::default());
     }
 
     /// Checks if an atomic batch is in progress.
     fn is_atomic_in_progress(&self) -> bool {
-        self.id_map().is_atomic_in_progress()
-            || self.reverse_id_map().is_atomic_in_progress()
-            || self.constant_map().is_atomic_in_progress()
-            || self.public_map().is_atomic_in_progress()
-            || self.private_map().is_atomic_in_progress()
-            || self.record_map().is_atomic_in_progress()
-            || self.record_tag_map().is_atomic_in_progress()
-            || self.external_record_map().is_atomic_in_progress()
+        self.atomic_state().depth.load(Ordering::SeqCst) > 0
     }
 
-    /// Aborts an atomic batch write operation.
+    /// Aborts the innermost atomic batch nesting level, restoring its savepoint and leaving any
+    /// enclosing, still-open level untouched. The real underlying atomic batch is only torn down
+    /// once the outermost level is the one being aborted.
     fn abort_atomic(&self) {
-        self.id_map().abort_atomic();
-        self.reverse_id_map().abort_atomic();
-        self.constant_map().abort_atomic();
-        self.public_map().abort_atomic();
-        self.private_map().abort_atomic();
-        self.record_map().abort_atomic();
-        self.record_tag_map().abort_atomic();
-        self.external_record_map().abort_atomic();
+        let state = self.atomic_state();
+        if state.depth.load(Ordering::SeqCst) == 0 {
+            // No atomic batch is in progress; there is nothing to abort.
+            return;
+        }
+
+        if let Some(savepoint) = state.savepoints.write().unwrap().pop() {
+            let _ = self.restore(savepoint);
+        }
+
+        if state.depth.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.id_map().abort_atomic();
+            self.reverse_id_map().abort_atomic();
+            self.constant_map().abort_atomic();
+            self.public_map().abort_atomic();
+            self.private_map().abort_atomic();
+            self.record_map().abort_atomic();
+            self.record_tag_map().abort_atomic();
+            self.external_record_map().abort_atomic();
+        }
     }
 
-    /// Finishes an atomic batch write operation.
+    /// Finishes an atomic batch write operation. The underlying batch is only committed once the
+    /// outermost `start_atomic` call's matching `finish_atomic` is reached.
     fn finish_atomic(&self) -> Result<()> {
-        self.id_map().finish_atomic()?;
-        self.reverse_id_map().finish_atomic()?;
-        self.constant_map().finish_atomic()?;
-        self.public_map().finish_atomic()?;
-        self.private_map().finish_atomic()?;
-        self.record_map().finish_atomic()?;
-        self.record_tag_map().finish_atomic()?;
-        self.external_record_map().finish_atomic()
+        let state = self.atomic_state();
+        if state.depth.load(Ordering::SeqCst) == 0 {
+            // No atomic batch is in progress; there is nothing to finish.
+            return Ok(());
+        }
+
+        // This level is committing into its enclosing level (or to storage); drop its savepoint.
+        state.savepoints.write().unwrap().pop();
+
+        if state.depth.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.id_map().finish_atomic()?;
+            self.reverse_id_map().finish_atomic()?;
+            self.constant_map().finish_atomic()?;
+            self.public_map().finish_atomic()?;
+            self.private_map().finish_atomic()?;
+            self.record_map().finish_atomic()?;
+            self.record_tag_map().finish_atomic()?;
+            self.external_record_map().finish_atomic()?;
+        }
+        Ok(())
+    }
+
+    /// Discards the writes made since the most recent `start_atomic` call, without affecting any
+    /// enclosing, still-open levels.
+    fn rollback_to_savepoint(&self) -> Result<()> {
+        let state = self.atomic_state();
+        if state.depth.load(Ordering::SeqCst) == 0 {
+            // No atomic batch is in progress; there is nothing to roll back to.
+            return Ok(());
+        }
+
+        if let Some(savepoint) = state.savepoints.write().unwrap().pop() {
+            self.restore(savepoint)?;
+        }
+        // Re-open a fresh, empty undo log for the current level, so it may be committed or rolled back again.
+        state.savepoints.write().unwrap().push(InputSavepoint::default());
+        Ok(())
     }
 
     /// Stores the given `(transition ID, input)` pair into storage.
     fn insert(&self, transition_id: N::TransitionID, inputs: &[Input<N>]) -> Result<()> {
         atomic_write_batch!(self, {
             // Store the input IDs.
+            record_undo!(self, id_map, transition_id)?;
             self.id_map().insert(transition_id, inputs.iter().map(Input::id).copied().collect())?;
 
             // Store the inputs.
             for input in inputs {
                 // Store the reverse input ID.
+                record_undo!(self, reverse_id_map, *input.id())?;
                 self.reverse_id_map().insert(*input.id(), transition_id)?;
                 // Store the input.
                 match input.clone() {
-                    Input::Constant(input_id, constant) => self.constant_map().insert(input_id, constant)?,
-                    Input::Public(input_id, public) => self.public_map().insert(input_id, public)?,
-                    Input::Private(input_id, private) => self.private_map().insert(input_id, private)?,
+                    Input::Constant(input_id, constant) => {
+                        record_undo!(self, constant_map, input_id)?;
+                        self.constant_map().insert(input_id, constant)?
+                    }
+                    Input::Public(input_id, public) => {
+                        record_undo!(self, public_map, input_id)?;
+                        self.public_map().insert(input_id, public)?
+                    }
+                    Input::Private(input_id, private) => {
+                        record_undo!(self, private_map, input_id)?;
+                        self.private_map().insert(input_id, private)?
+                    }
                     Input::Record(serial_number, tag, origin) => {
                         // Store the record tag.
+                        record_undo!(self, record_tag_map, tag)?;
                         self.record_tag_map().insert(tag, serial_number)?;
                         // Store the record.
+                        record_undo!(self, record_map, serial_number)?;
                         self.record_map().insert(serial_number, (tag, origin))?
                     }
-                    Input::ExternalRecord(input_id) => self.external_record_map().insert(input_id, ())?,
+                    Input::ExternalRecord(input_id) => {
+                        record_undo!(self, external_record_map, input_id)?;
+                        self.external_record_map().insert(input_id, ())?
+                    }
                 }
             }
 
@@ -162,23 +385,31 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
 
         atomic_write_batch!(self, {
             // Remove the input IDs.
+            record_undo!(self, id_map, *transition_id)?;
             self.id_map().remove(transition_id)?;
 
             // Remove the inputs.
             for input_id in input_ids {
                 // Remove the reverse input ID.
+                record_undo!(self, reverse_id_map, input_id)?;
                 self.reverse_id_map().remove(&input_id)?;
 
                 // If the input is a record, remove the record tag.
                 if let Some(record) = self.record_map().get(&input_id)? {
+                    record_undo!(self, record_tag_map, record.0)?;
                     self.record_tag_map().remove(&record.0)?;
                 }
 
                 // Remove the input.
+                record_undo!(self, constant_map, input_id)?;
                 self.constant_map().remove(&input_id)?;
+                record_undo!(self, public_map, input_id)?;
                 self.public_map().remove(&input_id)?;
+                record_undo!(self, private_map, input_id)?;
                 self.private_map().remove(&input_id)?;
+                record_undo!(self, record_map, input_id)?;
                 self.record_map().remove(&input_id)?;
+                record_undo!(self, external_record_map, input_id)?;
                 self.external_record_map().remove(&input_id)?;
             }
 
@@ -197,6 +428,162 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Prunes the constant, public, and private input *values* for the given `transition ID`, while
+    /// keeping the `id_map`, `reverse_id_map`, record tags, and serial numbers intact for membership
+    /// and consistency checks.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the input IDs, so the value maps can be targeted without knowing their variant.
+        let input_ids = self.get_ids(transition_id)?;
+
+        atomic_write_batch!(self, {
+            for input_id in input_ids {
+                // Replace the stored plaintext/ciphertext value with `None`, if present.
+                if self.constant_map().contains_key(&input_id)? {
+                    record_undo!(self, constant_map, input_id)?;
+                    self.constant_map().insert(input_id, None)?;
+                }
+                if self.public_map().contains_key(&input_id)? {
+                    record_undo!(self, public_map, input_id)?;
+                    self.public_map().insert(input_id, None)?;
+                }
+                if self.private_map().contains_key(&input_id)? {
+                    record_undo!(self, private_map, input_id)?;
+                    self.private_map().insert(input_id, None)?;
+                }
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    /// Prunes the constant, public, and private input values for each of the given `transition IDs`.
+    /// Note: The caller is responsible for determining which transition IDs fall below the desired
+    /// pruning height, as this storage layer has no notion of block height.
+    fn prune_below<'a>(&self, transition_ids: impl Iterator<Item = &'a N::TransitionID>) -> Result<()>
+    where
+        N::TransitionID: 'a,
+    {
+        atomic_write_batch!(self, {
+            for transition_id in transition_ids {
+                self.prune(transition_id)?;
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    /// Returns `true` if the given `input ID` has had its value pruned.
+    fn is_pruned(&self, input_id: &Field<N>) -> Result<bool> {
+        if let Some(constant) = self.constant_map().get(input_id)? {
+            return Ok(constant.is_none());
+        }
+        if let Some(public) = self.public_map().get(input_id)? {
+            return Ok(public.is_none());
+        }
+        if let Some(private) = self.private_map().get(input_id)? {
+            return Ok(private.is_none());
+        }
+        Ok(false)
+    }
+
+    /// Walks every map and reports structural inconsistencies, without failing fast. This allows
+    /// operators to audit a migrated or recovered database.
+    fn verify(&self) -> Result<Vec<Inconsistency<N>>> {
+        let mut problems = Vec::new();
+
+        // (1) Check that every `reverse_id_map` entry has a matching `id_map` row that lists it.
+        for input_id in self.reverse_id_map().keys() {
+            let input_id = *input_id;
+            let transition_id = match self.reverse_id_map().get(&input_id)? {
+                Some(transition_id) => *transition_id,
+                None => continue,
+            };
+            let is_listed = match self.id_map().get(&transition_id)? {
+                Some(ids) => ids.iter().any(|id| *id == input_id),
+                None => false,
+            };
+            if !is_listed {
+                problems.push(Inconsistency::DanglingReverseId { input_id, transition_id });
+            }
+        }
+
+        // (2) Check that every `record_tag_map` entry points at a serial number stored under the same tag.
+        for tag in self.record_tag_map().keys() {
+            let tag = *tag;
+            let serial_number = match self.record_tag_map().get(&tag)? {
+                Some(serial_number) => *serial_number,
+                None => continue,
+            };
+            let matches = match self.record_map().get(&serial_number)? {
+                Some(record) => record.0 == tag,
+                None => false,
+            };
+            if !matches {
+                problems.push(Inconsistency::RecordTagMismatch { tag, serial_number });
+            }
+        }
+
+        // (3) Check that every `record_map` entry's tag points back at the same serial number.
+        for serial_number in self.record_map().keys() {
+            let serial_number = *serial_number;
+            let tag = match self.record_map().get(&serial_number)? {
+                Some(record) => record.0,
+                None => continue,
+            };
+            let matches = match self.record_tag_map().get(&tag)? {
+                Some(mapped_serial_number) => *mapped_serial_number == serial_number,
+                None => false,
+            };
+            if !matches {
+                problems.push(Inconsistency::RecordMissingTag { serial_number, tag });
+            }
+        }
+
+        // (4) Check that no input ID is present in more than one of the value maps, and gather the
+        // full set of known input IDs for the orphan check below.
+        let mut counts: HashMap<Field<N>, usize> = HashMap::new();
+        for input_id in self.constant_map().keys() {
+            *counts.entry(*input_id).or_default() += 1;
+        }
+        for input_id in self.public_map().keys() {
+            *counts.entry(*input_id).or_default() += 1;
+        }
+        for input_id in self.private_map().keys() {
+            *counts.entry(*input_id).or_default() += 1;
+        }
+        for input_id in self.record_map().keys() {
+            *counts.entry(*input_id).or_default() += 1;
+        }
+        for input_id in self.external_record_map().keys() {
+            *counts.entry(*input_id).or_default() += 1;
+        }
+        for (input_id, count) in &counts {
+            if *count > 1 {
+                problems.push(Inconsistency::DuplicateInputId { input_id: *input_id, count: *count });
+            }
+        }
+
+        // (5) Check that every `id_map` entry's input IDs exist in at least one value map.
+        for transition_id in self.id_map().keys() {
+            let transition_id = *transition_id;
+            let input_ids = match self.id_map().get(&transition_id)? {
+                Some(ids) => ids.to_vec(),
+                None => continue,
+            };
+            for input_id in input_ids {
+                if !counts.contains_key(&input_id) {
+                    problems.push(Inconsistency::OrphanedInputId { transition_id, input_id });
+                }
+            }
+        }
+
+        Ok(problems)
+    }
+
     /// Returns the input IDs for the given `transition ID`.
     fn get_ids(&self, transition_id: &N::TransitionID) -> Result<Vec<Field<N>>> {
         // Retrieve the input IDs.
@@ -277,6 +664,8 @@ pub struct InputMemory<N: Network> {
     external_record: MemoryMap<Field<N>, ()>,
     /// The optional development ID.
     dev: Option<u16>,
+    /// The atomic batch nesting state, shared across clones of this storage.
+    atomic_state: Arc<AtomicBatchState<N>>,
 }
 
 #[rustfmt::skip]
@@ -302,6 +691,7 @@ impl<N: Network> InputStorage<N> for InputMemory<N> {
             record_tag: MemoryMap::default(),
             external_record: MemoryMap::default(),
             dev,
+            atomic_state: Arc::new(AtomicBatchState::default()),
         })
     }
 
@@ -349,6 +739,11 @@ impl<N: Network> InputStorage<N> for InputMemory<N> {
     fn dev(&self) -> Option<u16> {
         self.dev
     }
+
+    /// Returns the atomic batch nesting state.
+    fn atomic_state(&self) -> &AtomicBatchState<N> {
+        &self.atomic_state
+    }
 }
 
 /// The transition input store.
@@ -430,6 +825,13 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
         self.storage.finish_atomic()
     }
 
+    /// Discards the writes made since the most recent `start_atomic` call, without affecting any
+    /// enclosing, still-open levels. This allows a caller-owned batch to recover from a failed
+    /// nested operation without aborting the entire outer transaction.
+    pub fn rollback_to_savepoint(&self) -> Result<()> {
+        self.storage.rollback_to_savepoint()
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
@@ -455,6 +857,32 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     }
 }
 
+impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
+    /// Prunes the constant, public, and private input values for the given `transition ID`, while
+    /// keeping the hash index (IDs, record tags, and serial numbers) intact.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
+    /// Prunes the constant, public, and private input values for each of the given `transition IDs`.
+    pub fn prune_below<'a>(&self, transition_ids: impl Iterator<Item = &'a N::TransitionID>) -> Result<()>
+    where
+        N::TransitionID: 'a,
+    {
+        self.storage.prune_below(transition_ids)
+    }
+
+    /// Returns `true` if the given `input ID` has had its value pruned.
+    pub fn is_pruned(&self, input_id: &Field<N>) -> Result<bool> {
+        self.storage.is_pruned(input_id)
+    }
+
+    /// Walks every map and reports structural inconsistencies, without failing fast.
+    pub fn verify(&self) -> Result<Vec<Inconsistency<N>>> {
+        self.storage.verify()
+    }
+}
+
 impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     /// Returns `true` if the given input ID exists.
     pub fn contains_input_id(&self, input_id: &Field<N>) -> Result<bool> {
@@ -546,6 +974,534 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     }
 }
 
+/// The default maximum number of entries held by a [`CachedInputStorage`]'s read cache.
+const DEFAULT_CACHE_CAPACITY: usize = 1 << 16;
+
+/// The default maximum number of entries held by a [`CachedInputStorage`]'s reverse-ID cache.
+/// Reverse-ID lookups are a much narrower part of the read path than reconstructing inputs, so the
+/// default is a quarter of the main cache's capacity rather than matching it entry-for-entry.
+const DEFAULT_REVERSE_ID_CACHE_CAPACITY: usize = DEFAULT_CACHE_CAPACITY / 4;
+
+/// A bounded, size-capped least-recently-used cache.
+///
+/// This is intentionally a minimal, dependency-free implementation: eviction scans for the
+/// least-recently-accessed entry, which is acceptable given the cache is capped to a small
+/// bounded capacity relative to the size of the backing storage.
+struct LruCache<K: Eq + Hash + Clone, V: Clone> {
+    capacity: usize,
+    /// The cached entries, each tagged with the clock value of its most recent access.
+    entries: HashMap<K, (V, u64)>,
+    /// A monotonically-increasing clock, bumped on every access.
+    clock: u64,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> LruCache<K, V> {
+    /// Initializes a new LRU cache with the given capacity.
+    fn new(capacity: usize) -> Self {
+        Self { capacity, entries: HashMap::new(), clock: 0 }
+    }
+
+    /// Returns this cache's configured capacity.
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns a clone of the cached value for the given key, if present.
+    fn get(&mut self, key: &K) -> Option<V> {
+        self.clock += 1;
+        let clock = self.clock;
+        self.entries.get_mut(key).map(|(value, last_used)| {
+            *last_used = clock;
+            value.clone()
+        })
+    }
+
+    /// Inserts the given key-value pair into the cache, evicting the least-recently-used entry if necessary.
+    fn insert(&mut self, key: K, value: V) {
+        self.clock += 1;
+        let clock = self.clock;
+        // Evict the least-recently-used entry if the cache is full and the key is new.
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            if let Some(lru_key) = self.entries.iter().min_by_key(|(_, (_, last_used))| *last_used).map(|(k, _)| k.clone()) {
+                self.entries.remove(&lru_key);
+            }
+        }
+        self.entries.insert(key, (value, clock));
+    }
+
+    /// Removes the cached value for the given key, if present.
+    fn remove(&mut self, key: &K) {
+        self.entries.remove(key);
+    }
+
+    /// Clears all cached entries.
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns a snapshot of the cache's current contents, for rollback purposes.
+    fn snapshot(&self) -> HashMap<K, (V, u64)> {
+        self.entries.clone()
+    }
+
+    /// Restores the cache to a previously-captured snapshot.
+    fn restore(&mut self, snapshot: HashMap<K, (V, u64)>) {
+        self.entries = snapshot;
+    }
+}
+
+/// Hit/miss counters for a [`CachedInputStorage`]'s read cache.
+#[derive(Default)]
+struct CacheStats {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// A snapshot of a [`CachedInputStorage`]'s caches, taken at `start_atomic` and restored on `abort_atomic`.
+struct CacheSnapshot<N: Network> {
+    inputs: HashMap<N::TransitionID, (Vec<Input<N>>, u64)>,
+    reverse_ids: HashMap<Field<N>, (N::TransitionID, u64)>,
+}
+
+/// A caching wrapper around an [`InputStorage`] implementation.
+///
+/// This sits between [`InputStore`] and the backing storage, holding a bounded LRU cache of
+/// recently-read `(transition ID -> inputs)` reconstructions, plus a smaller reverse-ID cache.
+/// Reads are served from the cache when possible; writes invalidate the affected keys; and an
+/// aborted atomic batch rolls the cache back to its pre-batch state, so cached data never
+/// diverges from committed storage.
+#[derive(Clone)]
+pub struct CachedInputStorage<N: Network, I: InputStorage<N>> {
+    /// The backing input storage.
+    storage: I,
+    /// The cache of `transition ID` to reconstructed `inputs`.
+    inputs_cache: Arc<RwLock<LruCache<N::TransitionID, Vec<Input<N>>>>>,
+    /// The cache of `input ID` to `transition ID`.
+    reverse_id_cache: Arc<RwLock<LruCache<Field<N>, N::TransitionID>>>,
+    /// A stack of cache snapshots, one per currently-open atomic batch nesting level, captured at
+    /// each `start_atomic` call, innermost last.
+    cache_snapshots: Arc<RwLock<Vec<CacheSnapshot<N>>>>,
+    /// The cache hit/miss counters.
+    stats: Arc<CacheStats>,
+}
+
+impl<N: Network, I: InputStorage<N>> CachedInputStorage<N, I> {
+    /// Initializes a new caching wrapper around the given storage, with the default inputs cache
+    /// capacity and a smaller default reverse-ID cache capacity.
+    pub fn new(storage: I) -> Self {
+        Self::with_capacities(storage, DEFAULT_CACHE_CAPACITY, DEFAULT_REVERSE_ID_CACHE_CAPACITY)
+    }
+
+    /// Initializes a new caching wrapper around the given storage, using `capacity` for both the
+    /// inputs cache and the reverse-ID cache. Prefer [`Self::with_capacities`] to give the
+    /// reverse-ID cache its own, typically smaller, capacity.
+    pub fn with_capacity(storage: I, capacity: usize) -> Self {
+        Self::with_capacities(storage, capacity, capacity)
+    }
+
+    /// Initializes a new caching wrapper around the given storage, with independent capacities for
+    /// the inputs cache and the reverse-ID cache.
+    pub fn with_capacities(storage: I, inputs_capacity: usize, reverse_id_capacity: usize) -> Self {
+        Self {
+            storage,
+            inputs_cache: Arc::new(RwLock::new(LruCache::new(inputs_capacity))),
+            reverse_id_cache: Arc::new(RwLock::new(LruCache::new(reverse_id_capacity))),
+            cache_snapshots: Arc::new(RwLock::new(Vec::new())),
+            stats: Arc::new(CacheStats::default()),
+        }
+    }
+
+    /// Returns the configured `(inputs cache, reverse-ID cache)` capacities.
+    pub fn capacity(&self) -> (usize, usize) {
+        (self.inputs_cache.read().unwrap().capacity(), self.reverse_id_cache.read().unwrap().capacity())
+    }
+
+    /// Captures the current cache contents as a snapshot for the savepoint being pushed.
+    fn cache_snapshot(&self) -> CacheSnapshot<N> {
+        CacheSnapshot {
+            inputs: self.inputs_cache.read().unwrap().snapshot(),
+            reverse_ids: self.reverse_id_cache.read().unwrap().snapshot(),
+        }
+    }
+
+    /// Restores the caches to match the given snapshot.
+    fn restore_cache(&self, snapshot: CacheSnapshot<N>) {
+        self.inputs_cache.write().unwrap().restore(snapshot.inputs);
+        self.reverse_id_cache.write().unwrap().restore(snapshot.reverse_ids);
+    }
+
+    /// Clears all cached entries, without affecting the backing storage.
+    pub fn clear_cache(&self) {
+        self.inputs_cache.write().unwrap().clear();
+        self.reverse_id_cache.write().unwrap().clear();
+    }
+
+    /// Returns the number of cache hits and misses observed so far.
+    pub fn cache_stats(&self) -> (u64, u64) {
+        (self.stats.hits.load(Ordering::Relaxed), self.stats.misses.load(Ordering::Relaxed))
+    }
+
+    /// Returns the cache hit rate, as a value in `[0, 1]`. Returns `0.0` if no reads have occurred.
+    pub fn cache_hit_rate(&self) -> f64 {
+        let (hits, misses) = self.cache_stats();
+        match hits + misses {
+            0 => 0.0,
+            total => hits as f64 / total as f64,
+        }
+    }
+
+    /// Invalidates the cached inputs and reverse IDs for the given `transition ID` and `input IDs`.
+    fn invalidate(&self, transition_id: &N::TransitionID, input_ids: &[Field<N>]) {
+        self.inputs_cache.write().unwrap().remove(transition_id);
+        let mut reverse_id_cache = self.reverse_id_cache.write().unwrap();
+        for input_id in input_ids {
+            reverse_id_cache.remove(input_id);
+        }
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network, I: InputStorage<N>> InputStorage<N> for CachedInputStorage<N, I> {
+    type IDMap = I::IDMap;
+    type ReverseIDMap = I::ReverseIDMap;
+    type ConstantMap = I::ConstantMap;
+    type PublicMap = I::PublicMap;
+    type PrivateMap = I::PrivateMap;
+    type RecordMap = I::RecordMap;
+    type RecordTagMap = I::RecordTagMap;
+    type ExternalRecordMap = I::ExternalRecordMap;
+
+    /// Initializes the transition input storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self::new(I::open(dev)?))
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        self.storage.id_map()
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        self.storage.reverse_id_map()
+    }
+
+    /// Returns the constant map.
+    fn constant_map(&self) -> &Self::ConstantMap {
+        self.storage.constant_map()
+    }
+
+    /// Returns the public map.
+    fn public_map(&self) -> &Self::PublicMap {
+        self.storage.public_map()
+    }
+
+    /// Returns the private map.
+    fn private_map(&self) -> &Self::PrivateMap {
+        self.storage.private_map()
+    }
+
+    /// Returns the record map.
+    fn record_map(&self) -> &Self::RecordMap {
+        self.storage.record_map()
+    }
+
+    /// Returns the record tag map.
+    fn record_tag_map(&self) -> &Self::RecordTagMap {
+        self.storage.record_tag_map()
+    }
+
+    /// Returns the external record map.
+    fn external_record_map(&self) -> &Self::ExternalRecordMap {
+        self.storage.external_record_map()
+    }
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16> {
+        self.storage.dev()
+    }
+
+    /// Returns the atomic batch nesting state of the backing storage.
+    fn atomic_state(&self) -> &AtomicBatchState<N> {
+        self.storage.atomic_state()
+    }
+
+    /// Starts an atomic batch write operation, pushing a cache snapshot for this nesting level so
+    /// it can be rolled back on abort without disturbing any enclosing level.
+    fn start_atomic(&self) {
+        self.cache_snapshots.write().unwrap().push(self.cache_snapshot());
+        self.storage.start_atomic();
+    }
+
+    /// Checks if an atomic batch is in progress.
+    fn is_atomic_in_progress(&self) -> bool {
+        self.storage.is_atomic_in_progress()
+    }
+
+    /// Aborts the innermost atomic batch nesting level, rolling the cache back to this level's
+    /// snapshot and leaving any enclosing, still-open level's cache contents untouched.
+    fn abort_atomic(&self) {
+        if let Some(snapshot) = self.cache_snapshots.write().unwrap().pop() {
+            self.restore_cache(snapshot);
+        }
+        self.storage.abort_atomic();
+    }
+
+    /// Finishes an atomic batch write operation.
+    fn finish_atomic(&self) -> Result<()> {
+        if !self.storage.is_atomic_in_progress() {
+            // No atomic batch is in progress; there is nothing to finish.
+            return Ok(());
+        }
+        let result = self.storage.finish_atomic();
+        self.cache_snapshots.write().unwrap().pop();
+        result
+    }
+
+    /// Discards the writes made since the most recent `start_atomic` call, without affecting any
+    /// enclosing, still-open levels.
+    fn rollback_to_savepoint(&self) -> Result<()> {
+        self.storage.rollback_to_savepoint()?;
+        if let Some(snapshot) = self.cache_snapshots.write().unwrap().pop() {
+            self.restore_cache(snapshot);
+        }
+        self.cache_snapshots.write().unwrap().push(self.cache_snapshot());
+        Ok(())
+    }
+
+    /// Stores the given `(transition ID, input)` pair into storage, invalidating the affected cache entries.
+    fn insert(&self, transition_id: N::TransitionID, inputs: &[Input<N>]) -> Result<()> {
+        self.storage.insert(transition_id, inputs)?;
+        let input_ids: Vec<_> = inputs.iter().map(|input| *input.id()).collect();
+        self.invalidate(&transition_id, &input_ids);
+        Ok(())
+    }
+
+    /// Removes the input for the given `transition ID`, invalidating the affected cache entries.
+    fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the affected input IDs before removing them, so the caches can be invalidated.
+        let input_ids = self.get_ids(transition_id)?;
+        self.storage.remove(transition_id)?;
+        self.invalidate(transition_id, &input_ids);
+        Ok(())
+    }
+
+    /// Returns the transition ID that contains the given `input ID`.
+    fn find_transition_id(&self, input_id: &Field<N>) -> Result<Option<N::TransitionID>> {
+        if let Some(transition_id) = self.reverse_id_cache.write().unwrap().get(input_id) {
+            self.stats.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(Some(transition_id));
+        }
+        self.stats.misses.fetch_add(1, Ordering::Relaxed);
+
+        let transition_id = self.storage.find_transition_id(input_id)?;
+        if let Some(transition_id) = transition_id {
+            self.reverse_id_cache.write().unwrap().insert(*input_id, transition_id);
+        }
+        Ok(transition_id)
+    }
+
+    /// Returns the input for the given `transition ID`.
+    fn get(&self, transition_id: &N::TransitionID) -> Result<Vec<Input<N>>> {
+        if let Some(inputs) = self.inputs_cache.write().unwrap().get(transition_id) {
+            self.stats.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(inputs);
+        }
+        self.stats.misses.fetch_add(1, Ordering::Relaxed);
+
+        let inputs = self.storage.get(transition_id)?;
+        self.inputs_cache.write().unwrap().insert(*transition_id, inputs.clone());
+        Ok(inputs)
+    }
+
+    /// Prunes the input values for the given `transition ID`, invalidating the stale cached reconstruction.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)?;
+        self.inputs_cache.write().unwrap().remove(transition_id);
+        Ok(())
+    }
+
+    /// Prunes the input values for each of the given `transition IDs`, invalidating the stale cached reconstructions.
+    fn prune_below<'a>(&self, transition_ids: impl Iterator<Item = &'a N::TransitionID>) -> Result<()>
+    where
+        N::TransitionID: 'a,
+    {
+        for transition_id in transition_ids {
+            self.prune(transition_id)?;
+        }
+        Ok(())
+    }
+}
+
+/// Synthetic benchmarking utilities for comparing [`InputStorage`] backends.
+///
+/// This deliberately avoids a new external harness dependency; it times operations directly with
+/// [`std::time::Instant`] so that any `InputStorage` implementation - in-memory or persistent - is
+/// measured the same way, against a temporary `dev` database that is torn down on drop.
+#[cfg(any(test, feature = "test"))]
+pub mod bench {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    /// The relative mix of input kinds, and the total count, to synthesize for a benchmark workload.
+    #[derive(Clone, Copy, Debug)]
+    pub struct WorkloadMix {
+        pub constant: u32,
+        pub public: u32,
+        pub private: u32,
+        pub record: u32,
+        pub external: u32,
+        pub count: usize,
+    }
+
+    impl Default for WorkloadMix {
+        fn default() -> Self {
+            Self { constant: 1, public: 1, private: 1, record: 1, external: 1, count: 1_000 }
+        }
+    }
+
+    /// The p50/p99 latency, in nanoseconds, observed for a single operation across a workload.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Percentiles {
+        pub p50_nanos: u128,
+        pub p99_nanos: u128,
+    }
+
+    impl Percentiles {
+        /// Computes the p50/p99 of the given per-operation latency samples.
+        fn from_samples(mut samples: Vec<Duration>) -> Self {
+            if samples.is_empty() {
+                return Self::default();
+            }
+            samples.sort_unstable();
+            let p50 = samples[samples.len() / 2].as_nanos();
+            let p99 = samples[(samples.len() * 99 / 100).min(samples.len() - 1)].as_nanos();
+            Self { p50_nanos: p50, p99_nanos: p99 }
+        }
+    }
+
+    /// A benchmark report for one `InputStorage` backend.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Report {
+        pub insert: Percentiles,
+        pub get: Percentiles,
+        pub find_transition_id: Percentiles,
+        pub remove: Percentiles,
+        pub atomic_batch_cycle: Percentiles,
+        pub throughput_ops_per_sec: f64,
+        /// An approximate in-memory size of the values written, for comparing batch write volume
+        /// across runs. Note: This is a `size_of`-based estimate, not an on-disk byte count.
+        pub approx_bytes_written: usize,
+    }
+
+    /// Generates a synthetic `(transition ID, input)` workload of the given mix. Every input ID,
+    /// transition ID, and record tag is freshly sampled, so the workload never collides with itself;
+    /// the constant/public/private payloads are left empty (the same `None` representation used by
+    /// [`InputStorage::prune`]), since only the storage access pattern - not the payload contents -
+    /// is relevant to this benchmark.
+    pub fn generate_workload<N: Network>(mix: WorkloadMix, rng: &mut TestRng) -> Vec<(N::TransitionID, Input<N>)>
+    where
+        N::TransitionID: Uniform,
+    {
+        let weights = [mix.constant, mix.public, mix.private, mix.record, mix.external];
+        let total_weight: u32 = weights.iter().sum();
+        assert!(total_weight > 0, "workload mix must have at least one non-zero ratio");
+
+        (0..mix.count)
+            .map(|i| {
+                // Pick a variant bucket according to the configured ratios.
+                let mut choice = (i as u32) % total_weight;
+                let mut variant = weights.len() - 1;
+                for (index, weight) in weights.iter().enumerate() {
+                    if choice < *weight {
+                        variant = index;
+                        break;
+                    }
+                    choice -= *weight;
+                }
+                let input = match variant {
+                    0 => Input::Constant(Uniform::rand(rng), None),
+                    1 => Input::Public(Uniform::rand(rng), None),
+                    2 => Input::Private(Uniform::rand(rng), None),
+                    3 => Input::Record(Uniform::rand(rng), Uniform::rand(rng), Origin::Commitment(Uniform::rand(rng))),
+                    _ => Input::ExternalRecord(Uniform::rand(rng)),
+                };
+                (Uniform::rand(rng), input)
+            })
+            .collect()
+    }
+
+    /// Measures `insert`, `get`, `remove`, `find_transition_id`, and a full `start_atomic`..`finish_atomic`
+    /// cycle against a fresh `I` backend, reporting per-operation p50/p99 latency, the throughput of
+    /// the insert phase, and the approximate total bytes written across the insert phase (a single
+    /// total, not a per-batch figure).
+    ///
+    /// Opens its own randomly-numbered `dev` backend for the duration of the run - rather than
+    /// taking an already-open backend from the caller - so that concurrent runs are unlikely to
+    /// collide on the same on-disk store, and the backend is torn down (via `Drop`, for backends that allocate
+    /// one) as soon as this function returns.
+    pub fn run<N: Network, I: InputStorage<N>>(mix: WorkloadMix) -> Result<Report>
+    where
+        N::TransitionID: Uniform,
+    {
+        let mut rng = TestRng::default();
+        let storage = I::open(Some(Uniform::rand(&mut rng)))?;
+        let workload = generate_workload::<N>(mix, &mut rng);
+
+        let mut insert_samples = Vec::with_capacity(workload.len());
+        let mut approx_bytes_written = 0usize;
+        let start = Instant::now();
+        for (transition_id, input) in &workload {
+            let op_start = Instant::now();
+            storage.insert(*transition_id, std::slice::from_ref(input))?;
+            insert_samples.push(op_start.elapsed());
+            approx_bytes_written += std::mem::size_of_val(input);
+        }
+        let elapsed = start.elapsed();
+
+        let mut get_samples = Vec::with_capacity(workload.len());
+        for (transition_id, _) in &workload {
+            let op_start = Instant::now();
+            let _ = storage.get(transition_id)?;
+            get_samples.push(op_start.elapsed());
+        }
+
+        let mut find_samples = Vec::with_capacity(workload.len());
+        for (_, input) in &workload {
+            let op_start = Instant::now();
+            let _ = storage.find_transition_id(input.id())?;
+            find_samples.push(op_start.elapsed());
+        }
+
+        let mut atomic_samples = Vec::with_capacity(workload.len());
+        for (transition_id, input) in &workload {
+            let op_start = Instant::now();
+            storage.start_atomic();
+            storage.remove(transition_id)?;
+            storage.insert(*transition_id, std::slice::from_ref(input))?;
+            storage.finish_atomic()?;
+            atomic_samples.push(op_start.elapsed());
+        }
+
+        let mut remove_samples = Vec::with_capacity(workload.len());
+        for (transition_id, _) in &workload {
+            let op_start = Instant::now();
+            storage.remove(transition_id)?;
+            remove_samples.push(op_start.elapsed());
+        }
+
+        Ok(Report {
+            insert: Percentiles::from_samples(insert_samples),
+            get: Percentiles::from_samples(get_samples),
+            find_transition_id: Percentiles::from_samples(find_samples),
+            remove: Percentiles::from_samples(remove_samples),
+            atomic_batch_cycle: Percentiles::from_samples(atomic_samples),
+            throughput_ops_per_sec: workload.len() as f64 / elapsed.as_secs_f64().max(f64::EPSILON),
+            approx_bytes_written,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -607,4 +1563,237 @@ mod tests {
             assert!(candidate.is_none());
         }
     }
+
+    #[test]
+    fn test_cached_storage_insert_get_remove() {
+        // Sample the transition inputs.
+        for (transition_id, input) in crate::ledger::transition::input::test_helpers::sample_inputs() {
+            // Initialize a new cached input storage.
+            let storage = CachedInputStorage::new(InputMemory::open(None).unwrap());
+
+            // Ensure the transition input does not exist.
+            assert!(storage.get(&transition_id).unwrap().is_empty());
+
+            // Insert the transition input.
+            storage.insert(transition_id, &[input.clone()]).unwrap();
+
+            // Retrieve the transition input twice, to exercise the cache.
+            assert_eq!(vec![input.clone()], storage.get(&transition_id).unwrap());
+            assert_eq!(vec![input.clone()], storage.get(&transition_id).unwrap());
+            // The initial empty lookup above also counted as a miss.
+            let (hits, misses) = storage.cache_stats();
+            assert_eq!(hits, 1);
+            assert_eq!(misses, 2);
+
+            // Remove the transition input, which must invalidate the cache entry.
+            storage.remove(&transition_id).unwrap();
+            assert!(storage.get(&transition_id).unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_prune() {
+        // Sample the transition inputs.
+        for (transition_id, input) in crate::ledger::transition::input::test_helpers::sample_inputs() {
+            // Initialize a new input store.
+            let input_store = InputMemory::open(None).unwrap();
+
+            // Insert the transition input.
+            input_store.insert(transition_id, &[input.clone()]).unwrap();
+
+            // Prune the transition input.
+            input_store.prune(&transition_id).unwrap();
+
+            // The input IDs must remain intact.
+            assert_eq!(vec![*input.id()], input_store.get_ids(&transition_id).unwrap());
+            // The input ID must be pruned, if it has a prunable value.
+            let is_value_input = matches!(input, Input::Constant(..) | Input::Public(..) | Input::Private(..));
+            assert_eq!(is_value_input, input_store.is_pruned(input.id()).unwrap());
+
+            // Pruning again must be a no-op.
+            input_store.prune(&transition_id).unwrap();
+            assert_eq!(is_value_input, input_store.is_pruned(input.id()).unwrap());
+
+            // `get` must still reconstruct the input after pruning: prunable kinds come back with
+            // their value replaced by `None`, while non-prunable kinds are unaffected.
+            let expected_after_prune = match input.clone() {
+                Input::Constant(input_id, _) => Input::Constant(input_id, None),
+                Input::Public(input_id, _) => Input::Public(input_id, None),
+                Input::Private(input_id, _) => Input::Private(input_id, None),
+                unchanged => unchanged,
+            };
+            assert_eq!(vec![expected_after_prune], input_store.get(&transition_id).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        // Sample the transition inputs.
+        for (transition_id, input) in crate::ledger::transition::input::test_helpers::sample_inputs() {
+            // Initialize a new input store.
+            let input_store = InputMemory::open(None).unwrap();
+
+            // An empty store has no inconsistencies.
+            assert!(input_store.verify().unwrap().is_empty());
+
+            // Insert the transition input.
+            input_store.insert(transition_id, &[input.clone()]).unwrap();
+
+            // A freshly-inserted input store has no inconsistencies.
+            assert!(input_store.verify().unwrap().is_empty());
+
+            // Pruning the input values must not introduce inconsistencies.
+            input_store.prune(&transition_id).unwrap();
+            assert!(input_store.verify().unwrap().is_empty());
+
+            // Corrupt the store by deleting the `id_map` row while leaving the reverse index behind.
+            input_store.id_map.remove(&transition_id).unwrap();
+
+            // The dangling `reverse_id_map` entry must be reported.
+            let problems = input_store.verify().unwrap();
+            assert_eq!(
+                problems,
+                vec![Inconsistency::DanglingReverseId { input_id: *input.id(), transition_id }]
+            );
+        }
+    }
+
+    /// Monomorphizes `bench::run` for `N`, inferred from `witness`. `bench::run` itself owns the
+    /// full lifecycle of its backend (it opens a fresh `dev` instance and drops it when done); this
+    /// wrapper only exists because the network type otherwise has nothing in the call to pin it to.
+    fn run_bench<N: Network>(_witness: &Input<N>, mix: bench::WorkloadMix) -> bench::Report {
+        bench::run::<N, InputMemory<N>>(mix).unwrap()
+    }
+
+    #[test]
+    #[ignore = "perf-tracking harness; run explicitly with `cargo test -- --ignored` to collect numbers"]
+    fn bench_input_memory() {
+        // Sample a transition to pin the network type, matching the pattern used by the other tests.
+        let (_, sample_input) = crate::ledger::transition::input::test_helpers::sample_inputs().into_iter().next().unwrap();
+
+        let mix = bench::WorkloadMix { count: 200, ..Default::default() };
+        let report = run_bench(&sample_input, mix);
+        println!("{report:#?}");
+    }
+
+    #[test]
+    fn test_cached_storage_abort_atomic_rolls_back_cache() {
+        // Sample the transition inputs.
+        for (transition_id, input) in crate::ledger::transition::input::test_helpers::sample_inputs() {
+            // Initialize a new cached input storage.
+            let storage = CachedInputStorage::new(InputMemory::open(None).unwrap());
+
+            // Insert and cache the transition input outside of any batch.
+            storage.insert(transition_id, &[input.clone()]).unwrap();
+            assert_eq!(vec![input.clone()], storage.get(&transition_id).unwrap());
+
+            // Start an atomic batch, remove the input, then abort the batch.
+            storage.start_atomic();
+            storage.remove(&transition_id).unwrap();
+            storage.abort_atomic();
+
+            // The cache must be rolled back to its pre-batch state.
+            assert_eq!(vec![input.clone()], storage.get(&transition_id).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_nested_atomic_batch() {
+        // Sample the transition inputs.
+        for (transition_id, input) in crate::ledger::transition::input::test_helpers::sample_inputs() {
+            // Initialize a new input store.
+            let input_store = InputMemory::open(None).unwrap();
+
+            // Start an outer atomic batch.
+            input_store.start_atomic();
+            input_store.insert(transition_id, &[input.clone()]).unwrap();
+
+            // Start a nested atomic batch, and finish it.
+            // The outer batch must remain in progress.
+            input_store.start_atomic();
+            assert!(input_store.is_atomic_in_progress());
+            input_store.finish_atomic().unwrap();
+            assert!(input_store.is_atomic_in_progress());
+
+            // Abort the outer batch; the insert must be rolled back entirely.
+            input_store.abort_atomic();
+            assert!(!input_store.is_atomic_in_progress());
+            assert!(input_store.get(&transition_id).unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_nested_abort_only_unwinds_inner_level() {
+        let mut inputs_iter = crate::ledger::transition::input::test_helpers::sample_inputs().into_iter();
+        let (transition_a, input_a) = inputs_iter.next().unwrap();
+        let (transition_b, input_b) = inputs_iter.next().unwrap();
+
+        let input_store = InputMemory::open(None).unwrap();
+
+        // Start an outer atomic batch and insert the first transition.
+        input_store.start_atomic();
+        input_store.insert(transition_a, &[input_a.clone()]).unwrap();
+
+        // Start a nested atomic batch, insert a second transition, then abort only the inner level.
+        input_store.start_atomic();
+        input_store.insert(transition_b, &[input_b.clone()]).unwrap();
+        input_store.abort_atomic();
+
+        // The outer batch must remain open, with the first transition's write still visible, and
+        // the second transition's write rolled back.
+        assert!(input_store.is_atomic_in_progress());
+        assert_eq!(vec![input_a.clone()], input_store.get(&transition_a).unwrap());
+        assert!(input_store.get(&transition_b).unwrap().is_empty());
+
+        // Finishing the outer batch must commit only the first transition's write.
+        input_store.finish_atomic().unwrap();
+        assert!(!input_store.is_atomic_in_progress());
+        assert_eq!(vec![input_a], input_store.get(&transition_a).unwrap());
+        assert!(input_store.get(&transition_b).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_atomic_operations_without_batch_are_no_ops() {
+        // Calling these without an open batch must be safe no-ops, not an underflow of `depth`
+        // that would permanently wedge future `start_atomic` calls.
+        let input_store = InputMemory::open(None).unwrap();
+
+        input_store.abort_atomic();
+        assert!(!input_store.is_atomic_in_progress());
+        input_store.finish_atomic().unwrap();
+        assert!(!input_store.is_atomic_in_progress());
+        input_store.rollback_to_savepoint().unwrap();
+        assert!(!input_store.is_atomic_in_progress());
+
+        // A subsequent real batch must still start and commit correctly afterwards.
+        input_store.start_atomic();
+        assert!(input_store.is_atomic_in_progress());
+        input_store.finish_atomic().unwrap();
+        assert!(!input_store.is_atomic_in_progress());
+    }
+
+    #[test]
+    fn test_rollback_to_savepoint() {
+        // Sample the transition inputs.
+        for (transition_id, input) in crate::ledger::transition::input::test_helpers::sample_inputs() {
+            // Initialize a new input store.
+            let input_store = InputMemory::open(None).unwrap();
+
+            // Start an atomic batch and insert the transition input.
+            input_store.start_atomic();
+            input_store.insert(transition_id, &[input.clone()]).unwrap();
+            assert_eq!(vec![input.clone()], input_store.get(&transition_id).unwrap());
+
+            // Roll back to the batch's savepoint; the insert must be undone, but the batch
+            // must remain open so the caller can retry.
+            input_store.rollback_to_savepoint().unwrap();
+            assert!(input_store.is_atomic_in_progress());
+            assert!(input_store.get(&transition_id).unwrap().is_empty());
+
+            // Finishing the batch now must commit an empty write.
+            input_store.finish_atomic().unwrap();
+            assert!(!input_store.is_atomic_in_progress());
+            assert!(input_store.get(&transition_id).unwrap().is_empty());
+        }
+    }
 }